@@ -14,6 +14,16 @@ pub trait Command {
     /// successfully or not
     fn execute(&self, arguments: &[String]) -> Result<bool, String>;
 
+    /// Execute the command with arguments and capture what
+    /// it has written to the standard output
+    ///
+    /// Returns an error when the command does not exist, when it
+    /// hasn't executed successfully or when the command doesn't
+    /// support reading its output
+    fn output(&self, _arguments: &[String]) -> Result<String, String> {
+        return Err("Reading the output of this command is not supported".to_string());
+    }
+
     /// Check if a specific command exists
     fn command_exists(&self, name: &str) -> bool {
         return match process::Command::new(&name).output() {
@@ -22,3 +32,16 @@ pub trait Command {
         };
     }
 }
+
+/// Run the process and read what it has written to the standard output
+///
+/// Returns the standard error as the error when the process fails
+pub fn read_output(command: &mut process::Command) -> Result<String, String> {
+    let output = command.output().map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    return Ok(String::from_utf8_lossy(&output.stdout).to_string());
+}
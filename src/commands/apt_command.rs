@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the apt command
+pub struct AptCommand;
+
+const APT_COMMAND: &str = "apt";
+
+impl Command for AptCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&APT_COMMAND) {
+            return Err("Apt command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&APT_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl AptCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the chown command
+pub struct ChownCommand;
+
+const CHOWN_COMMAND: &str = "chown";
+
+impl Command for ChownCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&CHOWN_COMMAND) {
+            return Err("Chown command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&CHOWN_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl ChownCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
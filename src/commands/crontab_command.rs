@@ -0,0 +1,36 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::{read_output, Command};
+
+/// Wrapper around the crontab command
+pub struct CrontabCommand;
+
+const CRONTAB_COMMAND: &str = "crontab";
+
+impl Command for CrontabCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&CRONTAB_COMMAND) {
+            return Err("Crontab command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&CRONTAB_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+
+    fn output(&self, arguments: &[String]) -> Result<String, String> {
+        if !self.command_exists(&CRONTAB_COMMAND) {
+            return Err("Crontab command can not be found!".to_string());
+        }
+
+        return read_output(process::Command::new(&CRONTAB_COMMAND).args(arguments));
+    }
+}
+
+impl CrontabCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
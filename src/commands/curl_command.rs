@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the curl command
+pub struct CurlCommand;
+
+const CURL_COMMAND: &str = "curl";
+
+impl Command for CurlCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&CURL_COMMAND) {
+            return Err("Curl command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&CURL_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl CurlCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the docker command
+pub struct DockerCommand;
+
+const DOCKER_COMMAND: &str = "docker";
+
+impl Command for DockerCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&DOCKER_COMMAND) {
+            return Err("Docker command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&DOCKER_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl DockerCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
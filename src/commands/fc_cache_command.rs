@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the fc-cache command
+pub struct FcCacheCommand;
+
+const FC_CACHE_COMMAND: &str = "fc-cache";
+
+impl Command for FcCacheCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&FC_CACHE_COMMAND) {
+            return Err("Fc-cache command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&FC_CACHE_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl FcCacheCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
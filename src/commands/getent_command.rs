@@ -0,0 +1,36 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::{read_output, Command};
+
+/// Wrapper around the getent command
+pub struct GetentCommand;
+
+const GETENT_COMMAND: &str = "getent";
+
+impl Command for GetentCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&GETENT_COMMAND) {
+            return Err("Getent command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&GETENT_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+
+    fn output(&self, arguments: &[String]) -> Result<String, String> {
+        if !self.command_exists(&GETENT_COMMAND) {
+            return Err("Getent command can not be found!".to_string());
+        }
+
+        return read_output(process::Command::new(&GETENT_COMMAND).args(arguments));
+    }
+}
+
+impl GetentCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the groupadd command
+pub struct GroupaddCommand;
+
+const GROUPADD_COMMAND: &str = "groupadd";
+
+impl Command for GroupaddCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&GROUPADD_COMMAND) {
+            return Err("Groupadd command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&GROUPADD_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl GroupaddCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
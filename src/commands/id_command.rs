@@ -0,0 +1,36 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::{read_output, Command};
+
+/// Wrapper around the id command
+pub struct IdCommand;
+
+const ID_COMMAND: &str = "id";
+
+impl Command for IdCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&ID_COMMAND) {
+            return Err("Id command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&ID_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+
+    fn output(&self, arguments: &[String]) -> Result<String, String> {
+        if !self.command_exists(&ID_COMMAND) {
+            return Err("Id command can not be found!".to_string());
+        }
+
+        return read_output(process::Command::new(&ID_COMMAND).args(arguments));
+    }
+}
+
+impl IdCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the macOS installer command
+pub struct InstallerCommand;
+
+const INSTALLER_COMMAND: &str = "installer";
+
+impl Command for InstallerCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&INSTALLER_COMMAND) {
+            return Err("Installer command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&INSTALLER_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl InstallerCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
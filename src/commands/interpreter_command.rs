@@ -0,0 +1,44 @@
+use std::collections::BTreeMap;
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around a script interpreter, e.g. `sh` or `python`,
+/// which is executed in a specific folder with additional
+/// environment variables
+pub struct InterpreterCommand {
+    interpreter: String,
+    working_directory: Option<String>,
+    environment: BTreeMap<String, String>
+}
+
+impl Command for InterpreterCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&self.interpreter) {
+            return Err(format!("{} command can not be found!", &self.interpreter));
+        }
+
+        let mut command = process::Command::new(&self.interpreter);
+        command.args(arguments).envs(&self.environment);
+
+        if let Some(working_directory) = &self.working_directory {
+            command.current_dir(&working_directory);
+        }
+
+        return Ok(command.output().is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl InterpreterCommand {
+    pub fn new(
+        interpreter: &str,
+        working_directory: &Option<String>,
+        environment: &BTreeMap<String, String>
+    ) -> Arc<dyn Command> {
+        return Arc::new(Self {
+            interpreter: interpreter.to_string(),
+            working_directory: working_directory.clone(),
+            environment: environment.clone()
+        });
+    }
+}
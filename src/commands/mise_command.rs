@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the mise command
+pub struct MiseCommand;
+
+const MISE_COMMAND: &str = "mise";
+
+impl Command for MiseCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&MISE_COMMAND) {
+            return Err("Mise command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&MISE_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl MiseCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
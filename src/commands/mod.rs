@@ -1,7 +1,49 @@
+mod apt_command;
+mod chown_command;
+mod crontab_command;
+mod curl_command;
+mod docker_command;
+mod fc_cache_command;
+mod getent_command;
 mod git_command;
+mod groupadd_command;
+mod id_command;
+mod installer_command;
+mod interpreter_command;
+mod mise_command;
+mod msiexec_command;
+mod podman_command;
+mod rpm_command;
+mod rustup_command;
+mod ssh_keygen_command;
+mod ssh_keyscan_command;
+mod systemctl_command;
+mod usermod_command;
+mod vscode_command;
 mod winget_command;
 mod wget_command;
 
+pub use apt_command::AptCommand;
+pub use chown_command::ChownCommand;
+pub use crontab_command::CrontabCommand;
+pub use curl_command::CurlCommand;
+pub use docker_command::DockerCommand;
+pub use fc_cache_command::FcCacheCommand;
+pub use getent_command::GetentCommand;
 pub use git_command::GitCommand;
+pub use groupadd_command::GroupaddCommand;
+pub use id_command::IdCommand;
+pub use installer_command::InstallerCommand;
+pub use interpreter_command::InterpreterCommand;
+pub use mise_command::MiseCommand;
+pub use msiexec_command::MsiexecCommand;
+pub use podman_command::PodmanCommand;
+pub use rpm_command::RpmCommand;
+pub use rustup_command::RustupCommand;
+pub use ssh_keygen_command::SshKeygenCommand;
+pub use ssh_keyscan_command::SshKeyscanCommand;
+pub use systemctl_command::SystemctlCommand;
+pub use usermod_command::UsermodCommand;
+pub use vscode_command::VscodeCommand;
 pub use winget_command::WingetCommand;
 pub use wget_command::WgetCommand;
\ No newline at end of file
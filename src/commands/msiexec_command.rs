@@ -0,0 +1,37 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the msiexec command
+pub struct MsiexecCommand;
+
+const MSIEXEC_COMMAND: &str = "msiexec";
+
+impl Command for MsiexecCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&MSIEXEC_COMMAND) {
+            return Err("Msiexec command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&MSIEXEC_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+
+    /// Running msiexec without arguments opens a dialog which waits for
+    /// the user, so `where` is used to look the command up instead
+    fn command_exists(&self, name: &str) -> bool {
+        return process::Command::new("where")
+            .arg(&name)
+            .output()
+            .is_ok_and(|output| output.status.success());
+    }
+}
+
+impl MsiexecCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the podman command
+pub struct PodmanCommand;
+
+const PODMAN_COMMAND: &str = "podman";
+
+impl Command for PodmanCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&PODMAN_COMMAND) {
+            return Err("Podman command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&PODMAN_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl PodmanCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
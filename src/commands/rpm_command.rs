@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the rpm command
+pub struct RpmCommand;
+
+const RPM_COMMAND: &str = "rpm";
+
+impl Command for RpmCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&RPM_COMMAND) {
+            return Err("Rpm command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&RPM_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl RpmCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the rustup command
+pub struct RustupCommand;
+
+const RUSTUP_COMMAND: &str = "rustup";
+
+impl Command for RustupCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&RUSTUP_COMMAND) {
+            return Err("Rustup command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&RUSTUP_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl RustupCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the ssh-keygen command
+pub struct SshKeygenCommand;
+
+const SSH_KEYGEN_COMMAND: &str = "ssh-keygen";
+
+impl Command for SshKeygenCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&SSH_KEYGEN_COMMAND) {
+            return Err("Ssh-keygen command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&SSH_KEYGEN_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl SshKeygenCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
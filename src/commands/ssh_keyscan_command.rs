@@ -0,0 +1,36 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::{read_output, Command};
+
+/// Wrapper around the ssh-keyscan command
+pub struct SshKeyscanCommand;
+
+const SSH_KEYSCAN_COMMAND: &str = "ssh-keyscan";
+
+impl Command for SshKeyscanCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&SSH_KEYSCAN_COMMAND) {
+            return Err("Ssh-keyscan command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&SSH_KEYSCAN_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+
+    fn output(&self, arguments: &[String]) -> Result<String, String> {
+        if !self.command_exists(&SSH_KEYSCAN_COMMAND) {
+            return Err("Ssh-keyscan command can not be found!".to_string());
+        }
+
+        return read_output(process::Command::new(&SSH_KEYSCAN_COMMAND).args(arguments));
+    }
+}
+
+impl SshKeyscanCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
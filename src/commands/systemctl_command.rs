@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the systemctl command
+pub struct SystemctlCommand;
+
+const SYSTEMCTL_COMMAND: &str = "systemctl";
+
+impl Command for SystemctlCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&SYSTEMCTL_COMMAND) {
+            return Err("Systemctl command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&SYSTEMCTL_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl SystemctlCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::process;
+use std::sync::Arc;
+use crate::command::Command;
+
+/// Wrapper around the usermod command
+pub struct UsermodCommand;
+
+const USERMOD_COMMAND: &str = "usermod";
+
+impl Command for UsermodCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&USERMOD_COMMAND) {
+            return Err("Usermod command can not be found!".to_string());
+        }
+
+        let command_output = process::Command::new(&USERMOD_COMMAND)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+}
+
+impl UsermodCommand {
+    pub fn new() -> Arc<dyn Command> {
+        return Arc::new(Self);
+    }
+}
\ No newline at end of file
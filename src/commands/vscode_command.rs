@@ -0,0 +1,55 @@
+use std::io::ErrorKind::NotFound;
+use std::process;
+use std::sync::Arc;
+use crate::command::{read_output, Command};
+
+/// Wrapper around the command line interface of Visual Studio Code
+/// or one of its variants, e.g. `code-insiders` or `codium`
+pub struct VscodeCommand {
+    editor: String
+}
+
+impl Command for VscodeCommand {
+    fn execute(&self, arguments: &[String]) -> Result<bool, String> {
+        if !self.command_exists(&self.editor) {
+            return Err(format!("{} command can not be found!", &self.editor));
+        }
+
+        let command_output = process::Command::new(&self.editor)
+            .args(arguments)
+            .output();
+
+        return Ok(command_output.is_ok_and(|output| output.status.success()));
+    }
+
+    fn output(&self, arguments: &[String]) -> Result<String, String> {
+        if !self.command_exists(&self.editor) {
+            return Err(format!("{} command can not be found!", &self.editor));
+        }
+
+        return read_output(process::Command::new(&self.editor).args(arguments));
+    }
+
+    /// Running the editor without arguments opens a new window,
+    /// so only its version is requested
+    fn command_exists(&self, name: &str) -> bool {
+        return match process::Command::new(&name).arg("--version").output() {
+            Ok(_) => true,
+            Err(e) => e.kind() != NotFound
+        };
+    }
+}
+
+impl VscodeCommand {
+    /// The command line interface is a batch file on Windows
+    pub fn new(editor: &str) -> Arc<dyn Command> {
+        let editor = match cfg!(windows) {
+            true => format!("{}.cmd", &editor),
+            false => editor.to_string()
+        };
+
+        return Arc::new(Self {
+            editor
+        });
+    }
+}
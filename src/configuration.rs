@@ -1,21 +1,470 @@
+use std::collections::BTreeMap;
+
 #[derive(Debug, serde::Deserialize, PartialEq)]
 pub struct ConfigFile {
     pub version: f32,
     pub configuration: Configuration
 }
 
-#[derive(Debug, serde::Deserialize, PartialEq)]
+#[derive(Debug, Default, serde::Deserialize, PartialEq)]
 pub struct Configuration {
+    #[serde(default, deserialize_with = "deserialize_directories")]
+    pub directories: Option<Vec<DirectoryConfiguration>>,
     pub packages: Option<Vec<PackageConfiguration>>,
     pub version_control: Option<Vec<VersionControlConfiguration>>,
-    pub downloads: Option<Vec<DownloadConfiguration>>
+    pub downloads: Option<Vec<DownloadConfiguration>>,
+    pub files: Option<Vec<FileCopyConfiguration>>,
+    pub scripts: Option<Vec<ScriptConfiguration>>,
+    pub dotfiles: Option<Vec<DotfilesConfiguration>>,
+    pub templates: Option<Vec<TemplateConfiguration>>,
+    pub environment: Option<EnvironmentConfiguration>,
+    pub line_in_file: Option<Vec<LineInFileConfiguration>>,
+    pub ssh: Option<SshConfiguration>,
+    pub services: Option<Vec<ServiceConfiguration>>,
+    pub schedules: Option<Vec<ScheduleConfiguration>>,
+    #[serde(default, deserialize_with = "deserialize_fonts")]
+    pub fonts: Option<Vec<DownloadFileConfiguration>>,
+    pub runtimes: Option<Vec<RuntimeConfiguration>>,
+    pub container_images: Option<Vec<ContainerImagesConfiguration>>,
+    #[serde(default, deserialize_with = "deserialize_groups")]
+    pub groups: Option<Vec<GroupConfiguration>>
 }
 
 #[derive(Debug, serde::Deserialize, PartialEq)]
 pub struct DownloadConfiguration {
+    #[serde(default = "default_download_manager")]
     pub download_manager: String,
     pub destination_folder: String,
-    pub files: Vec<String>
+    #[serde(deserialize_with = "deserialize_download_files")]
+    pub files: Vec<DownloadFileConfiguration>
+}
+
+/// A single file to download, either written as a plain URL
+/// or as a mapping with additional options
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct DownloadFileConfiguration {
+    /// Location of the file, can be omitted when
+    /// the file is downloaded from a GitHub release
+    #[serde(default)]
+    pub url: String,
+
+    /// Download the file from a GitHub release instead of the URL
+    pub github_release: Option<GithubReleaseConfiguration>,
+
+    /// Alternative locations of the file, which are tried
+    /// in order when the file can't be downloaded from the URL
+    #[serde(default)]
+    pub mirrors: Vec<String>,
+
+    /// Number of times a failed download is retried for every location
+    #[serde(default)]
+    pub retries: u32,
+
+    /// Seconds to wait before the first retry, doubled for every next retry
+    pub retry_delay: Option<u64>,
+
+    /// Name of the file inside the destination folder, defaults
+    /// to the name of the file in the URL
+    pub filename: Option<String>,
+
+    /// Expected SHA-256 digest of the downloaded file
+    pub sha256: Option<String>,
+
+    /// Expected SHA-512 digest of the downloaded file
+    pub sha512: Option<String>,
+
+    /// URL of a `SHA256SUMS` or `SHA512SUMS` file containing
+    /// the digest of the downloaded file
+    pub checksums_url: Option<String>,
+
+    /// Extract the downloaded archive into the destination folder,
+    /// either `true` or the extraction options
+    #[serde(default, deserialize_with = "deserialize_extract")]
+    pub extract: Option<ExtractConfiguration>,
+
+    /// Mark the downloaded file as executable
+    #[serde(default)]
+    pub executable: bool,
+
+    /// Location of a symbolic link to the downloaded file, when this
+    /// is a folder the link gets the name of the downloaded file
+    pub link_to: Option<String>,
+
+    /// Install the downloaded package using the installer
+    /// of the operating system
+    #[serde(default)]
+    pub install: bool
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct ExtractConfiguration {
+    /// Number of leading path components to remove from the archive members
+    #[serde(default)]
+    pub strip_components: usize,
+
+    /// Archive members to extract, after stripping the leading path components,
+    /// all members are extracted when empty
+    #[serde(default)]
+    pub members: Vec<String>
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct GithubReleaseConfiguration {
+    /// The repository in the `owner/repository` format
+    pub repository: String,
+
+    /// Tag of the release, defaults to the latest release
+    pub tag: Option<String>,
+
+    /// Name of the asset to download, `{os}` and `{arch}` are replaced with the
+    /// names of the current platform (e.g. `linux` and `amd64`) and `*` matches
+    /// any sequence of characters
+    pub asset: String,
+
+    /// Base URL of the GitHub API
+    #[serde(default = "default_github_api_url")]
+    pub api_url: String
+}
+
+/// A single file to copy from one location to another
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct FileCopyConfiguration {
+    /// Location of the file to copy
+    pub source: String,
+
+    /// Location to copy the file to, when this is a
+    /// folder the file keeps the name of the source
+    pub destination: String,
+
+    /// Permissions of the copied file as an octal number, e.g. `0644`
+    #[serde(default, deserialize_with = "deserialize_mode")]
+    pub mode: Option<u32>,
+
+    /// Replace the destination when it differs from the source
+    #[serde(default)]
+    pub overwrite: bool,
+
+    /// Keep the replaced destination next to it with a `.bak` extension
+    #[serde(default)]
+    pub backup: bool
+}
+
+/// A script to execute, either written inline or stored in a file
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct ScriptConfiguration {
+    /// Name of the script used when reporting its status,
+    /// defaults to the path or the first line of the script
+    pub name: Option<String>,
+
+    /// Body of the script
+    pub run: Option<String>,
+
+    /// Location of the script, used when the script isn't written inline
+    pub path: Option<String>,
+
+    /// Interpreter used to execute the script, either `sh`, `bash`, `pwsh`,
+    /// `powershell` or `python`, defaults to `sh` and `powershell` on Windows
+    pub interpreter: Option<String>,
+
+    /// Folder the script is executed in
+    pub working_directory: Option<String>,
+
+    /// Environment variables which are set for the script
+    #[serde(default)]
+    pub environment: BTreeMap<String, String>,
+
+    /// Skip the script when this file or folder exists
+    pub creates: Option<String>,
+
+    /// Skip the script when this command succeeds
+    pub unless: Option<String>
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct DotfilesConfiguration {
+    /// Folder containing the dotfiles, e.g. a cloned repository
+    pub source_folder: String,
+
+    /// Folder the dotfiles are linked into, defaults to the home directory
+    pub target_folder: Option<String>,
+
+    /// Files and folders inside the source folder to link
+    #[serde(deserialize_with = "deserialize_dotfile_links")]
+    pub links: Vec<DotfileLinkConfiguration>,
+
+    /// Remove links which have been created before
+    /// but are no longer part of the links
+    #[serde(default)]
+    pub prune: bool
+}
+
+/// A single file or folder to link, either written as a plain
+/// path or as a mapping with a different location for the link
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct DotfileLinkConfiguration {
+    /// Location of the file or folder inside the source folder
+    pub source: String,
+
+    /// Location of the link inside the target folder,
+    /// defaults to the location of the source
+    pub target: Option<String>
+}
+
+/// A file which is rendered from a template
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct TemplateConfiguration {
+    /// Location of the template
+    pub source: String,
+
+    /// Location of the rendered file, when this is a
+    /// folder the file keeps the name of the template
+    pub destination: String,
+
+    /// Variables which can be used in the template next to the
+    /// variables of the machine and the environment variables
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+
+    /// Permissions of the rendered file as an octal number, e.g. `0644`
+    #[serde(default, deserialize_with = "deserialize_mode")]
+    pub mode: Option<u32>
+}
+
+/// A folder which is created, including its parent folders
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct DirectoryConfiguration {
+    /// Location of the folder
+    pub path: String,
+
+    /// Permissions of the folder as an octal number, e.g. `0700`
+    #[serde(default, deserialize_with = "deserialize_mode")]
+    pub mode: Option<u32>,
+
+    /// Owner of the folder, either `user` or `user:group`
+    pub owner: Option<String>
+}
+
+/// Environment variables which are set for the current user
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct EnvironmentConfiguration {
+    /// Environment variables with their value, other
+    /// variables can be used in the value, e.g. `$HOME/go`
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+
+    /// Folders which are added in front of the `PATH`
+    #[serde(default)]
+    pub path: Vec<String>
+}
+
+/// A line or a block of lines inside of a file which isn't managed by upset
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct LineInFileConfiguration {
+    /// Location of the file to edit
+    pub path: String,
+
+    /// Line which should be present in, or absent from, the file
+    pub line: Option<String>,
+
+    /// Regular expression matching the line to replace or remove,
+    /// defaults to lines which are equal to the line
+    pub regexp: Option<String>,
+
+    /// Lines which are placed between upset marker comments,
+    /// used instead of a single line
+    pub block: Option<String>,
+
+    /// Name of the block, which is part of the marker comments, defaults to `block`
+    pub name: Option<String>,
+
+    /// Characters which start a comment in the file, defaults to `#`
+    pub comment: Option<String>,
+
+    /// Whether the line or the block should be `present` or `absent`
+    #[serde(default)]
+    pub state: LineState
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LineState {
+    #[default]
+    Present,
+    Absent
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct SshConfiguration {
+    /// Keys which are generated when they don't exist
+    #[serde(default)]
+    pub keys: Vec<SshKeyConfiguration>,
+
+    /// Hosts which are written to the ssh config of the user
+    #[serde(default)]
+    pub hosts: Vec<SshHostConfiguration>,
+
+    /// Entries for the known hosts of the user, either written as
+    /// `<host> <key type> <public key>`, e.g. the output of `ssh-keyscan`,
+    /// or as a host with the fingerprint of its key
+    #[serde(default, deserialize_with = "deserialize_known_hosts")]
+    pub known_hosts: Vec<KnownHostConfiguration>
+}
+
+/// A host which is added to the known hosts of the user
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct KnownHostConfiguration {
+    /// Name of the host, e.g. `github.com`
+    pub host: String,
+
+    /// Key of the host written as `<key type> <public key>`
+    pub key: Option<String>,
+
+    /// Fingerprint of the key of the host as shown by `ssh-keygen -l`, e.g.
+    /// `SHA256:+DiY3wvvV6TuJJhbpZisF/zLDA0zPMSvHdkr4UvCOqU`. The key is retrieved
+    /// using `ssh-keyscan` and only added when its fingerprint matches
+    pub fingerprint: Option<String>
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct SshKeyConfiguration {
+    /// Type of the key, defaults to `ed25519`
+    #[serde(rename = "type")]
+    pub key_type: Option<String>,
+
+    /// Location of the private key, defaults to `~/.ssh/id_<type>`
+    pub path: Option<String>,
+
+    /// Comment which is added to the public key
+    pub comment: Option<String>,
+
+    /// Passphrase protecting the private key, defaults to no passphrase
+    pub passphrase: Option<String>
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct SshHostConfiguration {
+    /// Pattern matching the host, e.g. `github.com` or `*.example.com`
+    pub host: String,
+
+    /// Real name of the host to connect to
+    pub hostname: Option<String>,
+
+    /// User to log in as
+    pub user: Option<String>,
+
+    /// Port to connect to
+    pub port: Option<u16>,
+
+    /// Private key used for authenticating
+    pub identity_file: Option<String>,
+
+    /// Other ssh options, e.g. `ForwardAgent: yes`
+    #[serde(default)]
+    pub options: BTreeMap<String, String>
+}
+
+/// A systemd unit, e.g. a service or a timer
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct ServiceConfiguration {
+    /// Name of the unit including its type, e.g. `syncthing.service`
+    pub name: String,
+
+    /// Whether the unit belongs to the `user` or the `system`, defaults to `user`
+    #[serde(default)]
+    pub scope: ServiceScope,
+
+    /// Contents of the unit file
+    pub unit: Option<String>,
+
+    /// Location of the unit file, used when the contents aren't written inline
+    pub source: Option<String>,
+
+    /// Whether the unit is started when booting or logging in
+    pub enabled: Option<bool>,
+
+    /// Whether the unit should be `started` or `stopped`
+    pub state: Option<ServiceState>
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceScope {
+    #[default]
+    User,
+    System
+}
+
+#[derive(Clone, Debug, serde::Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceState {
+    Started,
+    Stopped
+}
+
+/// A command which is executed periodically
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct ScheduleConfiguration {
+    /// Name of the schedule, which identifies the entry in the crontab
+    pub name: String,
+
+    /// When the command is executed in the cron format, e.g. `0 2 * * *` or `@daily`
+    pub schedule: String,
+
+    /// Command to execute
+    pub command: String
+}
+
+/// A toolchain which is installed through its version manager
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct RuntimeConfiguration {
+    /// Name of the version manager, e.g. `rustup` or `mise`
+    pub version_manager: String,
+
+    /// Version of the toolchain, e.g. `stable` for rustup or `node@20` for mise
+    pub version: String,
+
+    /// Use the toolchain by default
+    #[serde(default)]
+    pub default: bool,
+
+    /// Additional components of the toolchain, e.g. `clippy`
+    #[serde(default)]
+    pub components: Vec<String>,
+
+    /// Additional compilation targets of the toolchain, e.g. `wasm32-unknown-unknown`
+    #[serde(default)]
+    pub targets: Vec<String>
+}
+
+#[derive(Debug, serde::Deserialize, PartialEq)]
+pub struct ContainerImagesConfiguration {
+    /// Name of the container engine, e.g. `docker` or `podman`
+    pub container_engine: String,
+    #[serde(deserialize_with = "deserialize_container_images")]
+    pub images: Vec<ContainerImageConfiguration>
+}
+
+/// A single image to pull, either written as a plain reference
+/// or as a mapping with its digest
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct ContainerImageConfiguration {
+    /// Reference of the image, e.g. `postgres:16`
+    pub image: String,
+
+    /// Pin the image to a digest, e.g. `sha256:...`
+    pub digest: Option<String>
+}
+
+/// A group which should exist, either written as the name
+/// of the group or as a mapping with its members
+#[derive(Clone, Debug, Default, serde::Deserialize, PartialEq)]
+pub struct GroupConfiguration {
+    /// Name of the group, e.g. `docker`
+    pub name: String,
+
+    /// Users which should be a member of the group
+    #[serde(default)]
+    pub members: Vec<String>
 }
 
 #[derive(Debug, serde::Deserialize, PartialEq)]
@@ -30,4 +479,291 @@ pub struct VersionControlConfiguration {
     pub vcs: String,
     pub destination_folder: String,
     pub repositories: Vec<String>
-}
\ No newline at end of file
+}
+
+/// Use the built-in downloader when no download manager is configured
+fn default_download_manager() -> String {
+    return "http".to_string();
+}
+
+fn default_github_api_url() -> String {
+    return "https://api.github.com".to_string();
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum DownloadFileEntry {
+    Url(String),
+    File(Box<DownloadFileConfiguration>)
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum ContainerImageEntry {
+    Image(String),
+    Configured(ContainerImageConfiguration)
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum GroupEntry {
+    Name(String),
+    Group(GroupConfiguration)
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum KnownHostEntry {
+    Line(String),
+    Host(KnownHostConfiguration)
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum DotfileLinkEntry {
+    Path(String),
+    Link(DotfileLinkConfiguration)
+}
+
+/// Directories aren't an untagged enum, because buffering the entry
+/// turns a mode like `0o700` into a number before it can be read
+enum DirectoryEntry {
+    Path(String),
+    Directory(DirectoryConfiguration)
+}
+
+impl<'de> serde::Deserialize<'de> for DirectoryEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        return deserializer.deserialize_any(DirectoryEntryVisitor);
+    }
+}
+
+struct DirectoryEntryVisitor;
+
+impl<'de> serde::de::Visitor<'de> for DirectoryEntryVisitor {
+    type Value = DirectoryEntry;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        return formatter.write_str("a path or a directory");
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error
+    {
+        return Ok(DirectoryEntry::Path(value.to_string()));
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>
+    {
+        let deserializer = serde::de::value::MapAccessDeserializer::new(map);
+
+        return serde::Deserialize::deserialize(deserializer).map(DirectoryEntry::Directory);
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum ExtractEntry {
+    Enabled(bool),
+    Configured(ExtractConfiguration)
+}
+
+impl From<String> for DownloadFileConfiguration {
+    fn from(url: String) -> Self {
+        return Self {
+            url,
+            ..Default::default()
+        };
+    }
+}
+
+fn deserialize_download_files<'de, D>(deserializer: D) -> Result<Vec<DownloadFileConfiguration>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let entries: Vec<DownloadFileEntry> = serde::Deserialize::deserialize(deserializer)?;
+
+    return Ok(entries.into_iter()
+        .map(|entry| match entry {
+            DownloadFileEntry::Url(url) => DownloadFileConfiguration::from(url),
+            DownloadFileEntry::File(file) => *file
+        })
+        .collect());
+}
+
+/// Fonts are written as a local path or URL, or as a mapping with the download options
+fn deserialize_fonts<'de, D>(deserializer: D) -> Result<Option<Vec<DownloadFileConfiguration>>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let entries: Option<Vec<DownloadFileEntry>> = serde::Deserialize::deserialize(deserializer)?;
+
+    return Ok(entries.map(|entries| entries.into_iter()
+        .map(|entry| match entry {
+            DownloadFileEntry::Url(url) => DownloadFileConfiguration::from(url),
+            DownloadFileEntry::File(file) => *file
+        })
+        .collect()));
+}
+
+impl From<String> for DotfileLinkConfiguration {
+    fn from(source: String) -> Self {
+        return Self {
+            source,
+            ..Default::default()
+        };
+    }
+}
+
+fn deserialize_dotfile_links<'de, D>(deserializer: D) -> Result<Vec<DotfileLinkConfiguration>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let entries: Vec<DotfileLinkEntry> = serde::Deserialize::deserialize(deserializer)?;
+
+    return Ok(entries.into_iter()
+        .map(|entry| match entry {
+            DotfileLinkEntry::Path(source) => DotfileLinkConfiguration::from(source),
+            DotfileLinkEntry::Link(link) => link
+        })
+        .collect());
+}
+
+impl From<String> for ContainerImageConfiguration {
+    fn from(image: String) -> Self {
+        return Self {
+            image,
+            ..Default::default()
+        };
+    }
+}
+
+fn deserialize_container_images<'de, D>(deserializer: D) -> Result<Vec<ContainerImageConfiguration>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let entries: Vec<ContainerImageEntry> = serde::Deserialize::deserialize(deserializer)?;
+
+    return Ok(entries.into_iter()
+        .map(|entry| match entry {
+            ContainerImageEntry::Image(image) => ContainerImageConfiguration::from(image),
+            ContainerImageEntry::Configured(image) => image
+        })
+        .collect());
+}
+
+impl From<String> for GroupConfiguration {
+    fn from(name: String) -> Self {
+        return Self {
+            name,
+            ..Default::default()
+        };
+    }
+}
+
+fn deserialize_groups<'de, D>(deserializer: D) -> Result<Option<Vec<GroupConfiguration>>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let entries: Option<Vec<GroupEntry>> = serde::Deserialize::deserialize(deserializer)?;
+
+    return Ok(entries.map(|entries| entries.into_iter()
+        .map(|entry| match entry {
+            GroupEntry::Name(name) => GroupConfiguration::from(name),
+            GroupEntry::Group(group) => group
+        })
+        .collect()));
+}
+
+/// The host is the first part of the line and the key is the rest of it
+impl From<String> for KnownHostConfiguration {
+    fn from(line: String) -> Self {
+        return match line.trim().split_once(' ') {
+            Some((host, key)) => Self {
+                host: host.to_string(),
+                key: Some(key.trim().to_string()),
+                fingerprint: None
+            },
+            None => Self {
+                host: line.trim().to_string(),
+                ..Default::default()
+            }
+        };
+    }
+}
+
+fn deserialize_known_hosts<'de, D>(deserializer: D) -> Result<Vec<KnownHostConfiguration>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let entries: Vec<KnownHostEntry> = serde::Deserialize::deserialize(deserializer)?;
+
+    return Ok(entries.into_iter()
+        .map(|entry| match entry {
+            KnownHostEntry::Line(line) => KnownHostConfiguration::from(line),
+            KnownHostEntry::Host(known_host) => known_host
+        })
+        .collect());
+}
+
+impl From<String> for DirectoryConfiguration {
+    fn from(path: String) -> Self {
+        return Self {
+            path,
+            ..Default::default()
+        };
+    }
+}
+
+fn deserialize_directories<'de, D>(deserializer: D) -> Result<Option<Vec<DirectoryConfiguration>>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let entries: Option<Vec<DirectoryEntry>> = serde::Deserialize::deserialize(deserializer)?;
+
+    return Ok(entries.map(|entries| entries.into_iter()
+        .map(|entry| match entry {
+            DirectoryEntry::Path(path) => DirectoryConfiguration::from(path),
+            DirectoryEntry::Directory(directory) => directory
+        })
+        .collect()));
+}
+
+fn deserialize_extract<'de, D>(deserializer: D) -> Result<Option<ExtractConfiguration>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let entry: Option<ExtractEntry> = serde::Deserialize::deserialize(deserializer)?;
+
+    return Ok(match entry {
+        Some(ExtractEntry::Enabled(true)) => Some(ExtractConfiguration::default()),
+        Some(ExtractEntry::Configured(configuration)) => Some(configuration),
+        _ => None
+    });
+}
+
+/// Read the mode as octal digits, both when it's written as a number
+/// (`644`, `0644`, `0o644`) and when it's written as a string (`"0644"`)
+///
+/// The mode is read as the text of the YAML scalar, because YAML reads
+/// `644` as a decimal number and `0o644` as the number 420
+fn deserialize_mode<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: serde::Deserializer<'de>
+{
+    let entry: Option<String> = serde::Deserialize::deserialize(deserializer)?;
+    let Some(text) = entry else {
+        return Ok(None);
+    };
+    let digits = text.trim().trim_start_matches("0o");
+
+    return u32::from_str_radix(&digits, 8)
+        .map(Some)
+        .map_err(|_| serde::de::Error::custom(format!("Invalid mode {}", &digits)));
+}
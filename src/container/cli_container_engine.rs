@@ -0,0 +1,183 @@
+use std::any::Any;
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::ContainerImageConfiguration;
+use crate::container::container_engine::ContainerEngine;
+use crate::terminal::TerminalOutput;
+
+/// Container engine with a Docker compatible command line interface,
+/// which both Docker and Podman provide
+pub struct CliContainerEngine {
+    /// The command of the container engine
+    engine_command: Arc<dyn Command>,
+
+    /// Terminal output where the pull status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl ContainerEngine for CliContainerEngine {
+    fn pull(&self, images: &Vec<ContainerImageConfiguration>) {
+        for image in images {
+            self.pull_image(&get_reference(&image));
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl CliContainerEngine {
+    pub fn new(engine_command: &Arc<dyn Command>, terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn ContainerEngine> {
+        return Arc::new(Self {
+            engine_command: engine_command.clone(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn pull_image(&self, reference: &String) {
+        self.terminal_output.loading(&format!("Pulling {}", &reference));
+
+        if self.is_present(&reference) {
+            self.terminal_output.finish_with_success(&format!("{} is already present", &reference));
+            return;
+        }
+
+        let result = self.engine_command.execute(&["pull".to_string(), reference.to_string()]);
+        let is_success = result.is_ok_and(|result| result);
+
+        if is_success {
+            self.terminal_output.finish_with_success(&format!("Successfully pulled {}", &reference));
+        } else {
+            self.terminal_output.finish_with_warning(&format!("Unable to pull {}", &reference));
+        }
+    }
+
+    fn is_present(&self, reference: &String) -> bool {
+        return self.engine_command
+            .execute(&["image".to_string(), "inspect".to_string(), reference.to_string()])
+            .is_ok_and(|result| result);
+    }
+}
+
+/// Get the reference of the image, pinned to its digest when available
+fn get_reference(image: &ContainerImageConfiguration) -> String {
+    return match &image.digest {
+        Some(digest) => format!("{}@{}", &image.image, &digest),
+        None => image.image.to_string()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use mockall::Sequence;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::ContainerImageConfiguration;
+    use crate::container::cli_container_engine::CliContainerEngine;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output;
+
+    #[test]
+    fn pull_pulls_the_images_which_are_not_present() {
+        // Arrange
+        let images = vec!(ContainerImageConfiguration::from("postgres:16".to_string()));
+        let mut command_mock = MockCommand::new();
+        let mut sequence = Sequence::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &["image".to_string(), "inspect".to_string(), "postgres:16".to_string()])
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(false));
+        command_mock.expect_execute()
+            .withf(|args| args == &["pull".to_string(), "postgres:16".to_string()])
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CliContainerEngine::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_terminal_output()
+        );
+        sut.pull(&images);
+    }
+
+    #[test]
+    fn pull_pins_the_image_to_its_digest() {
+        // Arrange
+        let images = vec!(ContainerImageConfiguration {
+            image: "alpine".to_string(),
+            digest: Some("sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b".to_string())
+        });
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args.last().unwrap()
+                == "alpine@sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b")
+            .times(2)
+            .returning(|args| Ok(args[0] == "pull"));
+
+        // Act
+        let sut = CliContainerEngine::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_terminal_output()
+        );
+        sut.pull(&images);
+    }
+
+    #[test]
+    fn pull_skips_the_images_which_are_already_present() {
+        // Arrange
+        let images = vec!(ContainerImageConfiguration::from("postgres:16".to_string()));
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args[0] == "image")
+            .once()
+            .returning(|_| Ok(true));
+        command_mock.expect_execute()
+            .withf(|args| args[0] == "pull")
+            .never();
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message == "postgres:16 is already present")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CliContainerEngine::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.pull(&images);
+    }
+
+    #[test]
+    fn pull_calls_finish_with_warning_when_the_image_can_not_be_pulled() {
+        // Arrange
+        let images = vec!(ContainerImageConfiguration::from("postgres:16".to_string()));
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute().returning(|_| Err(String::default()));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message == "Unable to pull postgres:16")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CliContainerEngine::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.pull(&images);
+    }
+}
@@ -0,0 +1,13 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::ContainerImageConfiguration;
+
+/// Public methods to call on a specific container engine
+#[automock]
+pub trait ContainerEngine {
+    /// Pull the images which aren't present yet
+    fn pull(&self, images: &Vec<ContainerImageConfiguration>);
+
+    /// Get object reference
+    fn as_any(&self) -> &dyn Any;
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use crate::commands::{DockerCommand, PodmanCommand};
+use crate::container::cli_container_engine::CliContainerEngine;
+use crate::container::container_engine::ContainerEngine;
+use crate::terminal::TerminalOutput;
+
+pub trait ContainerEngineFactory {
+    fn get_container_engine(&self, name: &String) -> Option<Arc<dyn ContainerEngine>>;
+}
+
+pub struct ContainerEngineFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl ContainerEngineFactory for ContainerEngineFactoryImpl {
+    fn get_container_engine(&self, name: &String) -> Option<Arc<dyn ContainerEngine>> {
+        return match name.to_lowercase().as_str() {
+            "docker" => Some(CliContainerEngine::new(&DockerCommand::new(), &self.terminal_output)),
+            "podman" => Some(CliContainerEngine::new(&PodmanCommand::new(), &self.terminal_output)),
+            _ => None
+        };
+    }
+}
+
+impl ContainerEngineFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn ContainerEngineFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::container::cli_container_engine::CliContainerEngine;
+    use crate::container::container_engine_factory::ContainerEngineFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[test]
+    fn get_container_engine_returns_none_for_unsupported_container_engine() {
+        // Arrange
+        let name = "not-supported-container-engine".to_string();
+        let terminal_output = MockTerminalOutput::new();
+
+        // Act
+        let sut = ContainerEngineFactoryImpl::new(&(Arc::new(terminal_output) as Arc<dyn TerminalOutput>));
+        let result = sut.get_container_engine(&name);
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn get_container_engine_returns_cli_container_engine_for_docker() {
+        // Arrange
+        let name = "docker".to_string();
+        let terminal_output = MockTerminalOutput::new();
+
+        // Act
+        let sut = ContainerEngineFactoryImpl::new(&(Arc::new(terminal_output) as Arc<dyn TerminalOutput>));
+        let result = sut.get_container_engine(&name);
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<CliContainerEngine>());
+    }
+
+    #[test]
+    fn get_container_engine_returns_cli_container_engine_for_podman() {
+        // Arrange
+        let name = "podman".to_string();
+        let terminal_output = MockTerminalOutput::new();
+
+        // Act
+        let sut = ContainerEngineFactoryImpl::new(&(Arc::new(terminal_output) as Arc<dyn TerminalOutput>));
+        let result = sut.get_container_engine(&name);
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<CliContainerEngine>());
+    }
+}
@@ -0,0 +1,8 @@
+mod container_engine;
+mod cli_container_engine;
+mod container_engine_factory;
+
+pub use container_engine::MockContainerEngine;
+pub use container_engine::ContainerEngine;
+pub use container_engine_factory::ContainerEngineFactory;
+pub use container_engine_factory::ContainerEngineFactoryImpl;
\ No newline at end of file
@@ -0,0 +1,10 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::DirectoryConfiguration;
+
+/// Public methods to call for creating folders
+#[automock]
+pub trait DirectoryCreator {
+    fn create(&self, directories: &Vec<DirectoryConfiguration>);
+    fn as_any(&self) -> &dyn Any;
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use crate::commands::ChownCommand;
+use crate::directory::directory_creator::DirectoryCreator;
+use crate::directory::local_directory_creator::LocalDirectoryCreator;
+use crate::terminal::TerminalOutput;
+
+pub trait DirectoryCreatorFactory {
+    fn get_directory_creator(&self) -> Arc<dyn DirectoryCreator>;
+}
+
+pub struct DirectoryCreatorFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl DirectoryCreatorFactory for DirectoryCreatorFactoryImpl {
+    fn get_directory_creator(&self) -> Arc<dyn DirectoryCreator> {
+        return LocalDirectoryCreator::new(&ChownCommand::new(), &self.terminal_output);
+    }
+}
+
+impl DirectoryCreatorFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn DirectoryCreatorFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::directory::directory_creator_factory::DirectoryCreatorFactoryImpl;
+    use crate::directory::local_directory_creator::LocalDirectoryCreator;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[test]
+    fn get_directory_creator_returns_local_directory_creator() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = DirectoryCreatorFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_directory_creator();
+
+        // Assert
+        assert!(result.as_any().is::<LocalDirectoryCreator>());
+    }
+}
@@ -0,0 +1,274 @@
+use std::any::Any;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::DirectoryConfiguration;
+use crate::directory::directory_creator::DirectoryCreator;
+use crate::path::{expand_home_directory, set_mode};
+use crate::terminal::TerminalOutput;
+
+/// Outcome of creating a single folder
+#[derive(Debug, PartialEq)]
+enum CreateResult {
+    Created,
+    UpToDate
+}
+
+pub struct LocalDirectoryCreator {
+    /// The chown command
+    chown_command: Arc<dyn Command>,
+
+    /// Terminal output where the creation status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl DirectoryCreator for LocalDirectoryCreator {
+    fn create(&self, directories: &Vec<DirectoryConfiguration>) {
+        for directory in directories {
+            self.create_directory(&directory);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl LocalDirectoryCreator {
+    pub fn new(
+        chown_command: &Arc<dyn Command>,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn DirectoryCreator> {
+        return Arc::new(Self {
+            chown_command: chown_command.clone(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn create_directory(&self, directory: &DirectoryConfiguration) {
+        let path = expand_home_directory(&directory.path);
+
+        self.terminal_output.loading(&format!("Creating {}", &directory.path));
+
+        match self.create(Path::new(&path), &directory) {
+            Ok(CreateResult::Created) => self.terminal_output.finish_with_success(
+                &format!("Successfully created {}", &directory.path)
+            ),
+            Ok(CreateResult::UpToDate) => self.terminal_output.finish_with_success(
+                &format!("{} is up to date", &directory.path)
+            ),
+            Err(error) => self.terminal_output.finish_with_warning(
+                &format!("Unable to create {}, {}", &directory.path, &error)
+            )
+        }
+    }
+
+    /// Create the folder including its parent folders
+    /// and apply the permissions and the owner
+    fn create(&self, path: &Path, directory: &DirectoryConfiguration) -> Result<CreateResult, String> {
+        let mut result = CreateResult::UpToDate;
+
+        if !path.is_dir() {
+            fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+            result = CreateResult::Created;
+        }
+
+        if let Some(mode) = directory.mode {
+            set_mode(&path, mode).map_err(|e| e.to_string())?;
+        }
+
+        if let Some(owner) = &directory.owner {
+            self.change_owner(&path, &owner)?;
+        }
+
+        return Ok(result);
+    }
+
+    fn change_owner(&self, path: &Path, owner: &str) -> Result<(), String> {
+        let result = self.chown_command.execute(&[
+            owner.to_string(),
+            path.to_string_lossy().to_string()
+        ])?;
+
+        if !result {
+            return Err(format!("unable to change the owner to {}", &owner));
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::DirectoryConfiguration;
+    use crate::directory::local_directory_creator::LocalDirectoryCreator;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    #[test]
+    fn create_creates_the_folder_including_its_parent_folders() {
+        // Arrange
+        let folder = create_test_folder("directory", "create");
+        let directories = vec!(create_directory(&folder, "Git-repos/personal"));
+
+        // Act
+        let sut = LocalDirectoryCreator::new(&setup_command_mock(), &setup_terminal_output());
+        sut.create(&directories);
+
+        // Assert
+        assert!(folder.join("Git-repos/personal").is_dir());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn create_calls_finish_with_success_when_the_folder_is_up_to_date() {
+        // Arrange
+        let folder = create_test_folder("directory", "up-to-date");
+        let directories = vec!(create_directory(&folder, "bin"));
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::create_dir_all(folder.join("bin")).unwrap();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = LocalDirectoryCreator::new(
+            &setup_command_mock(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.create(&directories);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn create_calls_finish_with_warning_when_the_path_is_a_file() {
+        // Arrange
+        let folder = create_test_folder("directory", "file");
+        let directories = vec!(create_directory(&folder, "bin"));
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(folder.join("bin"), "upset").unwrap();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = LocalDirectoryCreator::new(
+            &setup_command_mock(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.create(&directories);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn create_sets_the_mode_of_the_folder() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange
+        let folder = create_test_folder("directory", "mode");
+        let directories = vec!(DirectoryConfiguration {
+            mode: Some(0o700),
+            ..create_directory(&folder, ".ssh")
+        });
+
+        // Act
+        let sut = LocalDirectoryCreator::new(&setup_command_mock(), &setup_terminal_output());
+        sut.create(&directories);
+
+        // Assert
+        let metadata = fs::metadata(folder.join(".ssh")).unwrap();
+        assert_eq!(0o700, metadata.permissions().mode() & 0o777);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn create_calls_chown_with_the_owner_and_the_folder() {
+        // Arrange
+        let folder = create_test_folder("directory", "owner");
+        let directories = vec!(DirectoryConfiguration {
+            owner: Some("bart:staff".to_string()),
+            ..create_directory(&folder, "projects")
+        });
+        let expected_path = folder.join("projects").to_string_lossy().to_string();
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(move |args| args == &["bart:staff".to_string(), expected_path.clone()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = LocalDirectoryCreator::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_terminal_output()
+        );
+        sut.create(&directories);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn create_calls_finish_with_warning_when_the_owner_can_not_be_changed() {
+        // Arrange
+        let folder = create_test_folder("directory", "owner-failed");
+        let directories = vec!(DirectoryConfiguration {
+            owner: Some("root".to_string()),
+            ..create_directory(&folder, "projects")
+        });
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute().returning(|_| Ok(false));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = LocalDirectoryCreator::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.create(&directories);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn create_directory(folder: &PathBuf, path: &str) -> DirectoryConfiguration {
+        return DirectoryConfiguration::from(folder.join(&path).to_string_lossy().to_string());
+    }
+
+    fn setup_command_mock() -> Arc<dyn Command> {
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute().never();
+
+        return Arc::new(command_mock);
+    }
+}
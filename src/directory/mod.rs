@@ -0,0 +1,8 @@
+mod directory_creator;
+mod local_directory_creator;
+mod directory_creator_factory;
+
+pub use directory_creator::MockDirectoryCreator;
+pub use directory_creator::DirectoryCreator;
+pub use directory_creator_factory::DirectoryCreatorFactory;
+pub use directory_creator_factory::DirectoryCreatorFactoryImpl;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::DotfilesConfiguration;
+
+/// Public methods to call on a specific dotfile manager
+#[automock]
+pub trait DotfileManager {
+    /// Link the dotfiles from the source folder into the target folder
+    fn link(&self, dotfiles: &DotfilesConfiguration);
+
+    /// Get object reference
+    fn as_any(&self) -> &dyn Any;
+}
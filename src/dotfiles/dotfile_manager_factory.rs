@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use crate::dotfiles::dotfile_manager::DotfileManager;
+use crate::dotfiles::symlink_dotfile_manager::SymlinkDotfileManager;
+use crate::terminal::TerminalOutput;
+
+pub trait DotfileManagerFactory {
+    fn get_dotfile_manager(&self) -> Arc<dyn DotfileManager>;
+}
+
+pub struct DotfileManagerFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl DotfileManagerFactory for DotfileManagerFactoryImpl {
+    fn get_dotfile_manager(&self) -> Arc<dyn DotfileManager> {
+        return SymlinkDotfileManager::new(&self.terminal_output);
+    }
+}
+
+impl DotfileManagerFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn DotfileManagerFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::dotfiles::dotfile_manager_factory::DotfileManagerFactoryImpl;
+    use crate::dotfiles::symlink_dotfile_manager::SymlinkDotfileManager;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[test]
+    fn get_dotfile_manager_returns_symlink_dotfile_manager() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = DotfileManagerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_dotfile_manager();
+
+        // Assert
+        assert!(result.as_any().is::<SymlinkDotfileManager>());
+    }
+}
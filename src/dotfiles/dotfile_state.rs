@@ -0,0 +1,91 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Name of the state file inside the target folder
+const STATE_FILE_NAME: &str = ".upset-dotfiles.yaml";
+
+/// Links which have been created in a target folder, stored as
+/// a YAML file which maps the source folders to their links
+pub struct DotfileState {
+    path: PathBuf
+}
+
+impl DotfileState {
+    pub fn new(target_folder: &Path) -> Self {
+        return Self {
+            path: target_folder.join(STATE_FILE_NAME)
+        };
+    }
+
+    /// Get the links which have been created for the source folder
+    pub fn get(&self, source_folder: &str) -> BTreeSet<String> {
+        return self.read().remove(source_folder).unwrap_or_default();
+    }
+
+    /// Store the links which have been created for the source folder
+    pub fn set(&self, source_folder: &str, links: BTreeSet<String>) -> Result<(), String> {
+        let mut entries = self.read();
+
+        if links.is_empty() {
+            if entries.remove(source_folder).is_none() {
+                return Ok(());
+            }
+        } else {
+            entries.insert(source_folder.to_string(), links);
+        }
+
+        let file = File::create(&self.path).map_err(|e| e.to_string())?;
+
+        return serde_yaml::to_writer(file, &entries).map_err(|e| e.to_string());
+    }
+
+    fn read(&self) -> BTreeMap<String, BTreeSet<String>> {
+        return fs::read_to_string(&self.path).ok()
+            .and_then(|contents| serde_yaml::from_str(&contents).ok())
+            .unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use crate::dotfiles::dotfile_state::DotfileState;
+    use crate::test_support::{create_test_folder, delete_test_folder};
+
+    #[test]
+    fn get_returns_no_links_when_there_is_no_state() {
+        // Arrange
+        let folder = create_test_folder("dotfile-state", "no-state");
+
+        // Act
+        let sut = DotfileState::new(&folder);
+        let result = sut.get("/home/upset/dotfiles");
+
+        // Assert
+        assert!(result.is_empty());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn get_returns_the_links_of_the_source_folder() {
+        // Arrange
+        let folder = create_test_folder("dotfile-state", "set");
+        let links = BTreeSet::from(["/home/upset/.gitconfig".to_string()]);
+
+        // Act
+        let sut = DotfileState::new(&folder);
+        sut.set("/home/upset/dotfiles", links.clone()).unwrap();
+        sut.set("/home/upset/work-dotfiles", BTreeSet::from(["/home/upset/.npmrc".to_string()])).unwrap();
+        let result = sut.get("/home/upset/dotfiles");
+
+        // Assert
+        assert_eq!(links, result);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+}
@@ -0,0 +1,9 @@
+mod dotfile_manager;
+mod dotfile_state;
+mod symlink_dotfile_manager;
+mod dotfile_manager_factory;
+
+pub use dotfile_manager::MockDotfileManager;
+pub use dotfile_manager::DotfileManager;
+pub use dotfile_manager_factory::DotfileManagerFactory;
+pub use dotfile_manager_factory::DotfileManagerFactoryImpl;
\ No newline at end of file
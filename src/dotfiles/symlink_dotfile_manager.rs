@@ -0,0 +1,438 @@
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::configuration::{DotfileLinkConfiguration, DotfilesConfiguration};
+use crate::dotfiles::dotfile_manager::DotfileManager;
+use crate::dotfiles::dotfile_state::DotfileState;
+use crate::path::{backup_path, create_symlink, expand_home_directory};
+use crate::terminal::TerminalOutput;
+
+/// Outcome of linking a single dotfile
+#[derive(Debug, PartialEq)]
+enum LinkResult {
+    Linked,
+    UpToDate,
+    BackedUp(PathBuf)
+}
+
+pub struct SymlinkDotfileManager {
+    /// Terminal output where the link status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl DotfileManager for SymlinkDotfileManager {
+    fn link(&self, dotfiles: &DotfilesConfiguration) {
+        let source_folder = PathBuf::from(expand_home_directory(&dotfiles.source_folder));
+        let source_folder = fs::canonicalize(&source_folder).unwrap_or(source_folder);
+        let target_folder = PathBuf::from(expand_home_directory(
+            dotfiles.target_folder.as_deref().unwrap_or("~")
+        ));
+        let state = DotfileState::new(&target_folder);
+        let state_key = source_folder.to_string_lossy().to_string();
+        let mut links = BTreeSet::new();
+
+        for link in &dotfiles.links {
+            let target = get_target(&target_folder, &link);
+
+            self.link_dotfile(&source_folder.join(&link.source), &target);
+            links.insert(target.to_string_lossy().to_string());
+        }
+
+        let previous_links: Vec<String> = state.get(&state_key).into_iter()
+            .filter(|previous_link| !links.contains(previous_link))
+            .collect();
+
+        for previous_link in previous_links {
+            if !dotfiles.prune || !self.remove_link(&source_folder, Path::new(&previous_link)) {
+                links.insert(previous_link);
+            }
+        }
+
+        _ = state.set(&state_key, links);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl SymlinkDotfileManager {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn DotfileManager> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn link_dotfile(&self, source: &Path, target: &Path) {
+        let source_name = source.to_string_lossy();
+        let target_name = target.to_string_lossy();
+
+        self.terminal_output.loading(&format!("Linking {}", &target_name));
+
+        match create_dotfile_link(&source, &target) {
+            Ok(LinkResult::Linked) => self.terminal_output.finish_with_success(
+                &format!("Successfully linked {} to {}", &source_name, &target_name)
+            ),
+            Ok(LinkResult::UpToDate) => self.terminal_output.finish_with_success(
+                &format!("{} is up to date", &target_name)
+            ),
+            Ok(LinkResult::BackedUp(backup)) => self.terminal_output.finish_with_success(
+                &format!(
+                    "Successfully linked {} to {}, the existing file has been moved to {}",
+                    &source_name, &target_name, &backup.to_string_lossy()
+                )
+            ),
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => self.terminal_output.finish_with_warning(
+                &format!(
+                    "Unable to link {} to {}, the backup {} already exists",
+                    &source_name, &target_name, &backup_path(&target).to_string_lossy()
+                )
+            ),
+            Err(_) => self.terminal_output.finish_with_warning(
+                &format!("Unable to link {} to {}", &source_name, &target_name)
+            )
+        }
+    }
+
+    /// Remove a link which is no longer configured
+    ///
+    /// Links which have been replaced by something which doesn't point into
+    /// the source folder are left alone. Returns whether the link is gone
+    fn remove_link(&self, source_folder: &Path, link: &Path) -> bool {
+        let is_dotfile = fs::read_link(&link)
+            .is_ok_and(|target| target.starts_with(&source_folder));
+
+        if !is_dotfile {
+            return true;
+        }
+
+        self.terminal_output.loading(&format!("Removing {}", &link.to_string_lossy()));
+
+        return match remove_symlink(&link) {
+            Ok(_) => {
+                self.terminal_output.finish_with_success(&format!("Removed {}", &link.to_string_lossy()));
+                true
+            },
+            Err(_) => {
+                self.terminal_output.finish_with_warning(&format!("Unable to remove {}", &link.to_string_lossy()));
+                false
+            }
+        };
+    }
+}
+
+/// Get the location of the link, targets which are absolute
+/// or start with `~` aren't placed inside the target folder
+fn get_target(target_folder: &Path, link: &DotfileLinkConfiguration) -> PathBuf {
+    let target = link.target.as_deref().unwrap_or(&link.source);
+
+    return target_folder.join(expand_home_directory(&target));
+}
+
+/// Link the target to the source
+///
+/// Links which already point to the source are left untouched, other links
+/// are replaced and existing files and folders are moved to a backup. Files
+/// aren't linked when a backup exists already, so it's never overwritten
+fn create_dotfile_link(source: &Path, target: &Path) -> io::Result<LinkResult> {
+    let source = fs::canonicalize(&source)?;
+    let mut result = LinkResult::Linked;
+
+    if let Ok(metadata) = fs::symlink_metadata(&target) {
+        if metadata.file_type().is_symlink() {
+            if fs::read_link(&target)? == source {
+                return Ok(LinkResult::UpToDate);
+            }
+
+            remove_symlink(&target)?;
+        } else {
+            let backup = backup_path(&target);
+
+            // Renaming would silently replace the backup of an earlier run
+            if fs::symlink_metadata(&backup).is_ok() {
+                return Err(io::Error::from(io::ErrorKind::AlreadyExists));
+            }
+
+            fs::rename(&target, &backup)?;
+            result = LinkResult::BackedUp(backup);
+        }
+    }
+
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(&parent)?;
+    }
+
+    create_symlink(&source, &target)?;
+
+    return Ok(result);
+}
+
+/// Links to folders are removed as folders on Windows
+fn remove_symlink(link: &Path) -> io::Result<()> {
+    return fs::remove_file(&link).or_else(|_| fs::remove_dir(&link));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use crate::configuration::{DotfileLinkConfiguration, DotfilesConfiguration};
+    use crate::dotfiles::symlink_dotfile_manager::SymlinkDotfileManager;
+    use crate::path::create_symlink;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    #[test]
+    fn link_links_the_files_and_folders_into_the_target_folder() {
+        // Arrange
+        let (source_folder, target_folder) = create_test_folders("link");
+        let dotfiles = DotfilesConfiguration {
+            links: vec!(
+                DotfileLinkConfiguration {
+                    source: "gitconfig".to_string(),
+                    target: Some(".gitconfig".to_string())
+                },
+                DotfileLinkConfiguration::from(".config/nvim".to_string())
+            ),
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+
+        // Act
+        let sut = SymlinkDotfileManager::new(&setup_terminal_output());
+        sut.link(&dotfiles);
+
+        // Assert
+        assert_eq!("[user]", fs::read_to_string(target_folder.join(".gitconfig")).unwrap());
+        assert_eq!("set number", fs::read_to_string(target_folder.join(".config/nvim/init.vim")).unwrap());
+        assert!(fs::symlink_metadata(target_folder.join(".config/nvim")).unwrap().file_type().is_symlink());
+
+        // Teardown
+        delete_test_folders(&source_folder);
+    }
+
+    #[test]
+    fn link_moves_existing_files_to_a_backup() {
+        // Arrange
+        let (source_folder, target_folder) = create_test_folders("backup");
+        let dotfiles = DotfilesConfiguration {
+            links: vec!(DotfileLinkConfiguration::from("gitconfig".to_string())),
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+        fs::write(target_folder.join("gitconfig"), "[core]").unwrap();
+
+        // Act
+        let sut = SymlinkDotfileManager::new(&setup_terminal_output());
+        sut.link(&dotfiles);
+
+        // Assert
+        assert_eq!("[user]", fs::read_to_string(target_folder.join("gitconfig")).unwrap());
+        assert_eq!("[core]", fs::read_to_string(target_folder.join("gitconfig.bak")).unwrap());
+
+        // Teardown
+        delete_test_folders(&source_folder);
+    }
+
+    #[test]
+    fn link_does_not_overwrite_an_existing_backup() {
+        // Arrange
+        let (source_folder, target_folder) = create_test_folders("existing-backup");
+        let dotfiles = DotfilesConfiguration {
+            links: vec!(DotfileLinkConfiguration::from("gitconfig".to_string())),
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(target_folder.join("gitconfig"), "[core]").unwrap();
+        fs::write(target_folder.join("gitconfig.bak"), "[alias]").unwrap();
+
+        // Setup the expectations
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message.ends_with("gitconfig.bak already exists"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = SymlinkDotfileManager::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.link(&dotfiles);
+
+        // Assert
+        assert_eq!("[core]", fs::read_to_string(target_folder.join("gitconfig")).unwrap());
+        assert_eq!("[alias]", fs::read_to_string(target_folder.join("gitconfig.bak")).unwrap());
+
+        // Teardown
+        delete_test_folders(&source_folder);
+    }
+
+    #[test]
+    fn link_replaces_links_which_point_somewhere_else() {
+        // Arrange
+        let (source_folder, target_folder) = create_test_folders("replace");
+        let dotfiles = DotfilesConfiguration {
+            links: vec!(DotfileLinkConfiguration::from("gitconfig".to_string())),
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+        fs::write(target_folder.join("old-gitconfig"), "[core]").unwrap();
+        create_symlink(&target_folder.join("old-gitconfig"), &target_folder.join("gitconfig")).unwrap();
+
+        // Act
+        let sut = SymlinkDotfileManager::new(&setup_terminal_output());
+        sut.link(&dotfiles);
+
+        // Assert
+        assert_eq!("[user]", fs::read_to_string(target_folder.join("gitconfig")).unwrap());
+        assert!(!target_folder.join("gitconfig.bak").exists());
+
+        // Teardown
+        delete_test_folders(&source_folder);
+    }
+
+    #[test]
+    fn link_calls_finish_with_success_when_the_link_is_up_to_date() {
+        // Arrange
+        let (source_folder, target_folder) = create_test_folders("up-to-date");
+        let dotfiles = DotfilesConfiguration {
+            links: vec!(DotfileLinkConfiguration::from("gitconfig".to_string())),
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.starts_with("Successfully linked"))
+            .once()
+            .returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = SymlinkDotfileManager::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.link(&dotfiles);
+        sut.link(&dotfiles);
+
+        // Teardown
+        delete_test_folders(&source_folder);
+    }
+
+    #[test]
+    fn link_calls_finish_with_warning_when_the_source_does_not_exist() {
+        // Arrange
+        let (source_folder, target_folder) = create_test_folders("missing");
+        let dotfiles = DotfilesConfiguration {
+            links: vec!(DotfileLinkConfiguration::from("zshrc".to_string())),
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = SymlinkDotfileManager::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.link(&dotfiles);
+
+        // Assert
+        assert!(fs::symlink_metadata(target_folder.join("zshrc")).is_err());
+
+        // Teardown
+        delete_test_folders(&source_folder);
+    }
+
+    #[test]
+    fn link_removes_links_which_are_no_longer_configured_when_pruning() {
+        // Arrange
+        let (source_folder, target_folder) = create_test_folders("prune");
+        let previous_dotfiles = DotfilesConfiguration {
+            links: vec!(
+                DotfileLinkConfiguration::from("gitconfig".to_string()),
+                DotfileLinkConfiguration::from(".config/nvim".to_string())
+            ),
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+        let dotfiles = DotfilesConfiguration {
+            links: vec!(DotfileLinkConfiguration::from("gitconfig".to_string())),
+            prune: true,
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+
+        // Act
+        let sut = SymlinkDotfileManager::new(&setup_terminal_output());
+        sut.link(&previous_dotfiles);
+        sut.link(&dotfiles);
+
+        // Assert
+        assert!(fs::symlink_metadata(target_folder.join("gitconfig")).is_ok());
+        assert!(fs::symlink_metadata(target_folder.join(".config/nvim")).is_err());
+        assert!(source_folder.join(".config/nvim/init.vim").exists());
+
+        // Teardown
+        delete_test_folders(&source_folder);
+    }
+
+    #[test]
+    fn link_keeps_links_which_are_no_longer_configured_when_not_pruning() {
+        // Arrange
+        let (source_folder, target_folder) = create_test_folders("no-prune");
+        let previous_dotfiles = DotfilesConfiguration {
+            links: vec!(DotfileLinkConfiguration::from("gitconfig".to_string())),
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+        let dotfiles = DotfilesConfiguration {
+            links: vec!(),
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+        let pruned_dotfiles = DotfilesConfiguration {
+            links: vec!(),
+            prune: true,
+            ..create_dotfiles(&source_folder, &target_folder)
+        };
+
+        // Act
+        let sut = SymlinkDotfileManager::new(&setup_terminal_output());
+        sut.link(&previous_dotfiles);
+        sut.link(&dotfiles);
+        let is_kept = fs::symlink_metadata(target_folder.join("gitconfig")).is_ok();
+        sut.link(&pruned_dotfiles);
+
+        // Assert
+        assert!(is_kept);
+        assert!(fs::symlink_metadata(target_folder.join("gitconfig")).is_err());
+
+        // Teardown
+        delete_test_folders(&source_folder);
+    }
+
+    fn create_dotfiles(source_folder: &PathBuf, target_folder: &PathBuf) -> DotfilesConfiguration {
+        return DotfilesConfiguration {
+            source_folder: source_folder.to_string_lossy().to_string(),
+            target_folder: Some(target_folder.to_string_lossy().to_string()),
+            ..Default::default()
+        };
+    }
+
+    /// Create a source folder containing dotfiles and an empty target folder
+    fn create_test_folders(name: &str) -> (PathBuf, PathBuf) {
+        let folder = create_test_folder("dotfiles", &name);
+        let source_folder = folder.join("dotfiles");
+        let target_folder = folder.join("home");
+        fs::create_dir_all(source_folder.join(".config/nvim")).expect("Unable to create the test folder");
+        fs::create_dir_all(&target_folder).expect("Unable to create the test folder");
+        fs::write(source_folder.join("gitconfig"), "[user]").unwrap();
+        fs::write(source_folder.join(".config/nvim/init.vim"), "set number").unwrap();
+
+        return (source_folder, target_folder);
+    }
+
+    fn delete_test_folders(source_folder: &PathBuf) {
+        delete_test_folder(&source_folder.parent().unwrap().to_path_buf());
+    }
+}
@@ -0,0 +1,10 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::EnvironmentConfiguration;
+
+/// Public methods to call for setting environment variables
+#[automock]
+pub trait EnvironmentManager {
+    fn set(&self, environment: &EnvironmentConfiguration);
+    fn as_any(&self) -> &dyn Any;
+}
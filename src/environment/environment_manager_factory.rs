@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use crate::commands::InterpreterCommand;
+use crate::environment::environment_manager::EnvironmentManager;
+use crate::environment::profile_environment_manager::ProfileEnvironmentManager;
+use crate::environment::windows_environment_manager::WindowsEnvironmentManager;
+use crate::path::home_directory;
+use crate::terminal::TerminalOutput;
+
+pub trait EnvironmentManagerFactory {
+    fn get_environment_manager(&self) -> Option<Arc<dyn EnvironmentManager>>;
+}
+
+pub struct EnvironmentManagerFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl EnvironmentManagerFactory for EnvironmentManagerFactoryImpl {
+    /// Windows PowerShell is used on Windows, because it's part of every
+    /// installation while PowerShell 7 (`pwsh`) has to be installed separately
+    fn get_environment_manager(&self) -> Option<Arc<dyn EnvironmentManager>> {
+        if cfg!(windows) {
+            return Some(WindowsEnvironmentManager::new(
+                &InterpreterCommand::new("powershell", &None, &BTreeMap::new()),
+                &self.terminal_output
+            ));
+        }
+
+        return home_directory()
+            .map(|home_directory| ProfileEnvironmentManager::new(&home_directory, &self.terminal_output));
+    }
+}
+
+impl EnvironmentManagerFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn EnvironmentManagerFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::environment::environment_manager_factory::EnvironmentManagerFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[cfg(unix)]
+    #[test]
+    fn get_environment_manager_returns_profile_environment_manager() {
+        use crate::environment::profile_environment_manager::ProfileEnvironmentManager;
+
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = EnvironmentManagerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_environment_manager();
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<ProfileEnvironmentManager>());
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn get_environment_manager_returns_windows_environment_manager() {
+        use crate::environment::windows_environment_manager::WindowsEnvironmentManager;
+
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = EnvironmentManagerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_environment_manager();
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<WindowsEnvironmentManager>());
+    }
+}
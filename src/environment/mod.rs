@@ -0,0 +1,9 @@
+mod environment_manager;
+mod profile_environment_manager;
+mod windows_environment_manager;
+mod environment_manager_factory;
+
+pub use environment_manager::MockEnvironmentManager;
+pub use environment_manager::EnvironmentManager;
+pub use environment_manager_factory::EnvironmentManagerFactory;
+pub use environment_manager_factory::EnvironmentManagerFactoryImpl;
\ No newline at end of file
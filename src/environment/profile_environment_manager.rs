@@ -0,0 +1,267 @@
+use std::any::Any;
+use std::path::Path;
+use std::sync::Arc;
+use crate::configuration::EnvironmentConfiguration;
+use crate::environment::environment_manager::EnvironmentManager;
+use crate::managed_block::write_managed_block;
+use crate::terminal::TerminalOutput;
+
+const MANAGED_BLOCK_NAME: &str = "environment";
+
+/// Syntax used for setting variables in a shell profile
+#[derive(Debug, PartialEq)]
+enum ProfileSyntax {
+    Posix,
+    Fish
+}
+
+/// Shell profiles inside the home directory, the `.profile` is always
+/// written while the other profiles are only updated when they exist
+const PROFILES: [(&str, ProfileSyntax, bool); 4] = [
+    (".profile", ProfileSyntax::Posix, true),
+    (".bashrc", ProfileSyntax::Posix, false),
+    (".zshrc", ProfileSyntax::Posix, false),
+    (".config/fish/config.fish", ProfileSyntax::Fish, false)
+];
+
+pub struct ProfileEnvironmentManager {
+    /// Home directory containing the shell profiles
+    home_directory: String,
+
+    /// Terminal output where the status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl EnvironmentManager for ProfileEnvironmentManager {
+    fn set(&self, environment: &EnvironmentConfiguration) {
+        for (profile, syntax, required) in &PROFILES {
+            let path = Path::new(&self.home_directory).join(&profile);
+
+            if *required || path.exists() {
+                self.write_profile(&path, &format!("~/{}", &profile), &syntax, &environment);
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl ProfileEnvironmentManager {
+    pub fn new(home_directory: &str, terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn EnvironmentManager> {
+        return Arc::new(Self {
+            home_directory: home_directory.to_string(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn write_profile(&self, path: &Path, name: &str, syntax: &ProfileSyntax, environment: &EnvironmentConfiguration) {
+        let block = create_block(&syntax, &environment);
+
+        self.terminal_output.loading(&format!("Setting the environment in {}", &name));
+
+        match write_managed_block(&path, "#", &MANAGED_BLOCK_NAME, block.as_deref()) {
+            Ok(true) => self.terminal_output.finish_with_success(
+                &format!("Successfully set the environment in {}", &name)
+            ),
+            Ok(false) => self.terminal_output.finish_with_success(
+                &format!("{} is up to date", &name)
+            ),
+            Err(_) => self.terminal_output.finish_with_warning(
+                &format!("Unable to set the environment in {}", &name)
+            )
+        }
+    }
+}
+
+/// Create the lines setting the environment in the syntax of the shell,
+/// returns nothing when there is no environment to set
+fn create_block(syntax: &ProfileSyntax, environment: &EnvironmentConfiguration) -> Option<String> {
+    let mut lines = Vec::new();
+
+    for (name, value) in &environment.variables {
+        lines.push(match syntax {
+            ProfileSyntax::Posix => format!("export {}={}", &name, quote(&syntax, &value)),
+            ProfileSyntax::Fish => format!("set -gx {} {}", &name, quote(&syntax, &value))
+        });
+    }
+
+    if !environment.path.is_empty() {
+        let folders: Vec<String> = environment.path.iter()
+            .map(|folder| escape(&syntax, &folder))
+            .collect();
+
+        lines.push(match syntax {
+            ProfileSyntax::Posix => format!("export PATH=\"{}:$PATH\"", folders.join(":")),
+            ProfileSyntax::Fish => format!("set -gx PATH \"{}\" $PATH", folders.join("\" \""))
+        });
+    }
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    return Some(lines.join("\n"));
+}
+
+/// Quote the value so it can contain spaces while other variables are still expanded
+fn quote(syntax: &ProfileSyntax, value: &str) -> String {
+    return format!("\"{}\"", escape(&syntax, &value));
+}
+
+/// Escape the value for use between double quotes, a leading `~` is
+/// replaced by `$HOME` because the shell doesn't expand it between quotes
+///
+/// `$` is left alone so variables are still expanded. Fish doesn't run
+/// commands between backticks, so only POSIX shells need those escaped
+fn escape(syntax: &ProfileSyntax, value: &str) -> String {
+    let value = match value.strip_prefix('~') {
+        Some(path) if path.is_empty() || path.starts_with('/') => format!("$HOME{}", &path),
+        _ => value.to_string()
+    };
+    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+
+    return match syntax {
+        ProfileSyntax::Posix => value.replace('`', "\\`"),
+        ProfileSyntax::Fish => value
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::sync::Arc;
+    use crate::configuration::EnvironmentConfiguration;
+    use crate::environment::profile_environment_manager::{create_block, ProfileEnvironmentManager, ProfileSyntax};
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    #[test]
+    fn create_block_exports_the_variables_and_the_path_for_posix_shells() {
+        // Arrange
+        let environment = create_environment();
+
+        // Act
+        let result = create_block(&ProfileSyntax::Posix, &environment);
+
+        // Assert
+        assert_eq!(
+            Some("export EDITOR=\"nvim\"\nexport GOPATH=\"$HOME/go\"\nexport PATH=\"$HOME/bin:$GOPATH/bin:$PATH\"".to_string()),
+            result
+        );
+    }
+
+    #[test]
+    fn create_block_sets_the_variables_and_the_path_for_fish() {
+        // Arrange
+        let environment = create_environment();
+
+        // Act
+        let result = create_block(&ProfileSyntax::Fish, &environment);
+
+        // Assert
+        assert_eq!(
+            Some("set -gx EDITOR \"nvim\"\nset -gx GOPATH \"$HOME/go\"\nset -gx PATH \"$HOME/bin\" \"$GOPATH/bin\" $PATH".to_string()),
+            result
+        );
+    }
+
+    #[test]
+    fn create_block_escapes_quotes_in_the_value() {
+        // Arrange
+        let environment = EnvironmentConfiguration {
+            variables: BTreeMap::from([("GREETING".to_string(), "say \"hi\"".to_string())]),
+            ..Default::default()
+        };
+
+        // Act
+        let result = create_block(&ProfileSyntax::Posix, &environment);
+
+        // Assert
+        assert_eq!(Some("export GREETING=\"say \\\"hi\\\"\"".to_string()), result);
+    }
+
+    #[test]
+    fn create_block_only_escapes_backticks_for_posix_shells() {
+        // Arrange
+        let environment = EnvironmentConfiguration {
+            variables: BTreeMap::from([("PROMPT".to_string(), "`date` \\ \"$USER\"".to_string())]),
+            ..Default::default()
+        };
+
+        // Act
+        let posix = create_block(&ProfileSyntax::Posix, &environment);
+        let fish = create_block(&ProfileSyntax::Fish, &environment);
+
+        // Assert
+        assert_eq!(Some("export PROMPT=\"\\`date\\` \\\\ \\\"$USER\\\"\"".to_string()), posix);
+        assert_eq!(Some("set -gx PROMPT \"`date` \\\\ \\\"$USER\\\"\"".to_string()), fish);
+    }
+
+    #[test]
+    fn create_block_returns_none_without_an_environment() {
+        // Act
+        let result = create_block(&ProfileSyntax::Posix, &EnvironmentConfiguration::default());
+
+        // Assert
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    fn set_writes_the_profile_and_only_the_existing_shell_profiles() {
+        // Arrange
+        let folder = create_test_folder("environment", "profiles");
+        fs::write(folder.join(".bashrc"), "alias ll='ls -l'\n").unwrap();
+
+        // Act
+        let sut = ProfileEnvironmentManager::new(&folder.to_string_lossy(), &setup_terminal_output());
+        sut.set(&create_environment());
+
+        // Assert
+        assert!(fs::read_to_string(folder.join(".profile")).unwrap().contains("export EDITOR=\"nvim\""));
+        assert!(fs::read_to_string(folder.join(".bashrc")).unwrap().starts_with("alias ll='ls -l'\n# BEGIN upset environment"));
+        assert!(!folder.join(".zshrc").exists());
+        assert!(!folder.join(".config/fish/config.fish").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn set_calls_finish_with_success_when_the_profile_is_up_to_date() {
+        // Arrange
+        let folder = create_test_folder("environment", "up-to-date");
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        let sut = ProfileEnvironmentManager::new(&folder.to_string_lossy(), &setup_terminal_output());
+        sut.set(&create_environment());
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message == "~/.profile is up to date")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = ProfileEnvironmentManager::new(
+            &folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.set(&create_environment());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn create_environment() -> EnvironmentConfiguration {
+        return EnvironmentConfiguration {
+            variables: BTreeMap::from([
+                ("EDITOR".to_string(), "nvim".to_string()),
+                ("GOPATH".to_string(), "~/go".to_string())
+            ]),
+            path: vec!("~/bin".to_string(), "$GOPATH/bin".to_string())
+        };
+    }
+}
@@ -0,0 +1,193 @@
+use std::any::Any;
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::EnvironmentConfiguration;
+use crate::environment::environment_manager::EnvironmentManager;
+use crate::path::expand_home_directory;
+use crate::terminal::TerminalOutput;
+
+pub struct WindowsEnvironmentManager {
+    /// The Windows PowerShell command
+    powershell_command: Arc<dyn Command>,
+
+    /// Terminal output where the status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl EnvironmentManager for WindowsEnvironmentManager {
+    fn set(&self, environment: &EnvironmentConfiguration) {
+        for (name, value) in &environment.variables {
+            self.run(&name, &create_variable_script(&name, &expand_home_directory(&value)));
+        }
+
+        if !environment.path.is_empty() {
+            let folders: Vec<String> = environment.path.iter()
+                .map(|folder| expand_home_directory(&folder))
+                .collect();
+
+            self.run("PATH", &create_path_script(&folders));
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl WindowsEnvironmentManager {
+    pub fn new(
+        powershell_command: &Arc<dyn Command>,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn EnvironmentManager> {
+        return Arc::new(Self {
+            powershell_command: powershell_command.clone(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn run(&self, name: &str, script: &str) {
+        self.terminal_output.loading(&format!("Setting {}", &name));
+
+        let result = self.powershell_command.execute(&[
+            "-NoProfile".to_string(),
+            "-NonInteractive".to_string(),
+            "-Command".to_string(),
+            script.to_string()
+        ]);
+
+        if result.is_ok_and(|output| output) {
+            self.terminal_output.finish_with_success(&format!("Successfully set {}", &name));
+        } else {
+            self.terminal_output.finish_with_warning(&format!("Unable to set {}", &name));
+        }
+    }
+}
+
+/// Create the script storing the variable in the environment of the user
+fn create_variable_script(name: &str, value: &str) -> String {
+    return format!(
+        "[Environment]::SetEnvironmentVariable({}, {}, 'User')",
+        quote(&name),
+        quote(&value)
+    );
+}
+
+/// Create the script adding the folders in front of the `Path` of the
+/// user, folders which are already part of the `Path` are skipped
+///
+/// The `Path` is read from the registry without expanding it and written back
+/// as an expandable string, so entries like `%USERPROFILE%\bin` keep working
+/// when the variables they refer to change
+fn create_path_script(folders: &Vec<String>) -> String {
+    let folders: Vec<String> = folders.iter()
+        .map(|folder| quote(&folder))
+        .collect();
+
+    return format!(
+        "$path = @((Get-Item -Path 'HKCU:\\Environment').GetValue('Path', '', 'DoNotExpandEnvironmentNames') -split ';' | Where-Object {{ $_ }}); \
+        $folders = @({}) | Where-Object {{ $path -notcontains $_ }}; \
+        Set-ItemProperty -Path 'HKCU:\\Environment' -Name 'Path' -Type ExpandString -Value ((@($folders) + $path) -join ';')",
+        folders.join(", ")
+    );
+}
+
+/// Quote the value as a literal PowerShell string
+fn quote(value: &str) -> String {
+    return format!("'{}'", value.replace('\'', "''"));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+    use mockall::Sequence;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::EnvironmentConfiguration;
+    use crate::environment::windows_environment_manager::{create_path_script, create_variable_script, WindowsEnvironmentManager};
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output;
+
+    #[test]
+    fn create_variable_script_quotes_the_name_and_the_value() {
+        // Act
+        let result = create_variable_script("GREETING", "it's upset");
+
+        // Assert
+        assert_eq!("[Environment]::SetEnvironmentVariable('GREETING', 'it''s upset', 'User')", result);
+    }
+
+    #[test]
+    fn create_path_script_adds_the_folders_to_the_path_of_the_user() {
+        // Act
+        let result = create_path_script(&vec!("C:\\bin".to_string(), "C:\\go\\bin".to_string()));
+
+        // Assert
+        assert!(result.starts_with("$path = @((Get-Item -Path 'HKCU:\\Environment').GetValue('Path', '', 'DoNotExpandEnvironmentNames')"));
+        assert!(result.contains("$folders = @('C:\\bin', 'C:\\go\\bin')"));
+        assert!(result.ends_with("Set-ItemProperty -Path 'HKCU:\\Environment' -Name 'Path' -Type ExpandString -Value ((@($folders) + $path) -join ';')"));
+    }
+
+    #[test]
+    fn set_runs_a_script_for_each_variable_and_the_path() {
+        // Arrange
+        let environment = EnvironmentConfiguration {
+            variables: BTreeMap::from([
+                ("EDITOR".to_string(), "code".to_string()),
+                ("GOPATH".to_string(), "C:\\go".to_string())
+            ]),
+            path: vec!("C:\\go\\bin".to_string())
+        };
+        let mut command_mock = MockCommand::new();
+        let mut sequence = Sequence::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args[3].contains("'EDITOR', 'code'"))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+        command_mock.expect_execute()
+            .withf(|args| args[3].contains("'GOPATH', 'C:\\go'"))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+        command_mock.expect_execute()
+            .withf(|args| args[3].contains("'C:\\go\\bin'"))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = WindowsEnvironmentManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_terminal_output()
+        );
+        sut.set(&environment);
+    }
+
+    #[test]
+    fn set_calls_finish_with_warning_when_the_script_fails() {
+        // Arrange
+        let environment = EnvironmentConfiguration {
+            variables: BTreeMap::from([("EDITOR".to_string(), "code".to_string())]),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute().returning(|_| Ok(false));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message == "Unable to set EDITOR")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = WindowsEnvironmentManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.set(&environment);
+    }
+}
@@ -0,0 +1,10 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::FileCopyConfiguration;
+
+/// Public methods to call for copying files
+#[automock]
+pub trait FileCopy {
+    fn copy(&self, files: &Vec<FileCopyConfiguration>);
+    fn as_any(&self) -> &dyn Any;
+}
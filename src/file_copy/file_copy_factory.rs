@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use crate::file_copy::file_copy::FileCopy;
+use crate::file_copy::local_file_copy::LocalFileCopy;
+use crate::terminal::TerminalOutput;
+
+pub trait FileCopyFactory {
+    fn get_file_copy(&self) -> Arc<dyn FileCopy>;
+}
+
+pub struct FileCopyFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl FileCopyFactory for FileCopyFactoryImpl {
+    fn get_file_copy(&self) -> Arc<dyn FileCopy> {
+        return LocalFileCopy::new(&self.terminal_output);
+    }
+}
+
+impl FileCopyFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn FileCopyFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::file_copy::file_copy_factory::FileCopyFactoryImpl;
+    use crate::file_copy::local_file_copy::LocalFileCopy;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[test]
+    fn get_file_copy_returns_local_file_copy() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = FileCopyFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_file_copy();
+
+        // Assert
+        assert!(result.as_any().is::<LocalFileCopy>());
+    }
+}
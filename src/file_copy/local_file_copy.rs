@@ -0,0 +1,285 @@
+use std::any::Any;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::configuration::FileCopyConfiguration;
+use crate::file_copy::file_copy::FileCopy;
+use crate::path::{backup_path, expand_home_directory, resolve_destination, set_mode};
+use crate::terminal::TerminalOutput;
+
+/// Outcome of copying a single file
+#[derive(Debug, PartialEq)]
+enum CopyResult {
+    Copied,
+    UpToDate,
+    Exists
+}
+
+pub struct LocalFileCopy {
+    /// Terminal output where the copy status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl FileCopy for LocalFileCopy {
+    fn copy(&self, files: &Vec<FileCopyConfiguration>) {
+        for file in files {
+            self.copy_file(&file);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl LocalFileCopy {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn FileCopy> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn copy_file(&self, file: &FileCopyConfiguration) {
+        let source = PathBuf::from(expand_home_directory(&file.source));
+        let destination = resolve_destination(&expand_home_directory(&file.destination), &source);
+
+        self.terminal_output.loading(&format!("Copying {}", &file.source));
+
+        match copy(&source, &destination, &file) {
+            Ok(CopyResult::Copied) => self.terminal_output.finish_with_success(
+                &format!("Successfully copied {} to {}", &file.source, &file.destination)
+            ),
+            Ok(CopyResult::UpToDate) => self.terminal_output.finish_with_success(
+                &format!("{} is up to date", &file.destination)
+            ),
+            Ok(CopyResult::Exists) => self.terminal_output.finish_with_warning(
+                &format!("{} already exists", &file.destination)
+            ),
+            Err(_) => self.terminal_output.finish_with_warning(
+                &format!("Unable to copy {} to {}", &file.source, &file.destination)
+            )
+        }
+    }
+}
+
+/// Copy the source to the destination
+///
+/// A destination with the same contents as the source is left untouched,
+/// other existing destinations are only replaced when overwriting is enabled
+fn copy(source: &Path, destination: &Path, file: &FileCopyConfiguration) -> io::Result<CopyResult> {
+    let contents = fs::read(&source)?;
+    let mut result = CopyResult::Copied;
+
+    match fs::read(&destination) {
+        Ok(existing) if existing == contents => result = CopyResult::UpToDate,
+        Ok(_) if !file.overwrite => return Ok(CopyResult::Exists),
+        Ok(_) if file.backup => fs::copy(&destination, backup_path(&destination)).map(|_| ())?,
+        _ => ()
+    }
+
+    if result == CopyResult::Copied {
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(&parent)?;
+        }
+
+        fs::write(&destination, &contents)?;
+    }
+
+    if let Some(mode) = file.mode {
+        set_mode(&destination, mode)?;
+    }
+
+    return Ok(result);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use mockall::Sequence;
+    use crate::configuration::FileCopyConfiguration;
+    use crate::file_copy::local_file_copy::LocalFileCopy;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    #[test]
+    fn copy_copies_the_source_to_the_destination() {
+        // Arrange
+        let folder = create_test_folder("file-copy", "copy");
+        let files = vec!(create_file_copy(&folder, "upset.yaml", "copy/upset.yaml"));
+        fs::write(folder.join("upset.yaml"), "upset").unwrap();
+
+        // Act
+        let sut = LocalFileCopy::new(&setup_terminal_output());
+        sut.copy(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(folder.join("copy/upset.yaml")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn copy_copies_the_source_into_the_destination_folder() {
+        // Arrange
+        let folder = create_test_folder("file-copy", "folder");
+        let files = vec!(create_file_copy(&folder, "upset.yaml", "config/"));
+        fs::write(folder.join("upset.yaml"), "upset").unwrap();
+
+        // Act
+        let sut = LocalFileCopy::new(&setup_terminal_output());
+        sut.copy(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(folder.join("config/upset.yaml")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn copy_does_not_overwrite_the_destination_by_default() {
+        // Arrange
+        let folder = create_test_folder("file-copy", "no-overwrite");
+        let files = vec!(create_file_copy(&folder, "upset.yaml", "existing.yaml"));
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(folder.join("upset.yaml"), "upset").unwrap();
+        fs::write(folder.join("existing.yaml"), "existing").unwrap();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message.ends_with("already exists"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = LocalFileCopy::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.copy(&files);
+
+        // Assert
+        assert_eq!("existing", fs::read_to_string(folder.join("existing.yaml")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn copy_overwrites_the_destination_and_keeps_a_backup_when_configured() {
+        // Arrange
+        let folder = create_test_folder("file-copy", "backup");
+        let files = vec!(FileCopyConfiguration {
+            overwrite: true,
+            backup: true,
+            ..create_file_copy(&folder, "upset.yaml", "existing.yaml")
+        });
+        fs::write(folder.join("upset.yaml"), "upset").unwrap();
+        fs::write(folder.join("existing.yaml"), "existing").unwrap();
+
+        // Act
+        let sut = LocalFileCopy::new(&setup_terminal_output());
+        sut.copy(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(folder.join("existing.yaml")).unwrap());
+        assert_eq!("existing", fs::read_to_string(folder.join("existing.yaml.bak")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn copy_calls_finish_with_success_when_the_destination_is_up_to_date() {
+        // Arrange
+        let folder = create_test_folder("file-copy", "up-to-date");
+        let files = vec!(FileCopyConfiguration {
+            backup: true,
+            ..create_file_copy(&folder, "upset.yaml", "existing.yaml")
+        });
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(folder.join("upset.yaml"), "upset").unwrap();
+        fs::write(folder.join("existing.yaml"), "upset").unwrap();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = LocalFileCopy::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.copy(&files);
+
+        // Assert
+        assert!(!folder.join("existing.yaml.bak").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_sets_the_mode_of_the_destination() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange
+        let folder = create_test_folder("file-copy", "mode");
+        let files = vec!(FileCopyConfiguration {
+            mode: Some(0o600),
+            ..create_file_copy(&folder, "upset.yaml", "private.yaml")
+        });
+        fs::write(folder.join("upset.yaml"), "upset").unwrap();
+
+        // Act
+        let sut = LocalFileCopy::new(&setup_terminal_output());
+        sut.copy(&files);
+
+        // Assert
+        let metadata = fs::metadata(folder.join("private.yaml")).unwrap();
+        assert_eq!(0o600, metadata.permissions().mode() & 0o777);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn copy_calls_loading_before_finish_with_warning_when_the_source_does_not_exist() {
+        // Arrange
+        let folder = create_test_folder("file-copy", "missing");
+        let files = vec!(create_file_copy(&folder, "missing.yaml", "upset.yaml"));
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        let mut sequence = Sequence::new();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+
+        // Act
+        let sut = LocalFileCopy::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.copy(&files);
+
+        // Assert
+        assert!(!folder.join("upset.yaml").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn create_file_copy(folder: &PathBuf, source: &str, destination: &str) -> FileCopyConfiguration {
+        return FileCopyConfiguration {
+            source: folder.join(&source).to_string_lossy().to_string(),
+            destination: format!("{}/{}", folder.to_string_lossy(), &destination),
+            ..Default::default()
+        };
+    }
+}
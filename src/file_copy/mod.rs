@@ -0,0 +1,8 @@
+mod file_copy;
+mod local_file_copy;
+mod file_copy_factory;
+
+pub use file_copy::MockFileCopy;
+pub use file_copy::FileCopy;
+pub use file_copy_factory::FileCopyFactory;
+pub use file_copy_factory::FileCopyFactoryImpl;
\ No newline at end of file
@@ -0,0 +1,599 @@
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+use std::thread;
+use flate2::read::GzDecoder;
+use crate::configuration::ExtractConfiguration;
+
+#[derive(Debug, PartialEq)]
+enum ArchiveType {
+    Tar,
+    TarGz,
+    TarXz,
+    Zip
+}
+
+/// Extract the archive into the destination folder
+///
+/// The type of the archive is determined by its file name, supported
+/// archives are `.zip`, `.tar`, `.tar.gz`, `.tgz`, `.tar.xz` and `.txz`
+pub fn extract_archive(
+    archive: &Path,
+    destination_folder: &Path,
+    configuration: &ExtractConfiguration
+) -> Result<(), String> {
+    let archive_type = get_archive_type(&archive)
+        .ok_or(format!("Unsupported archive {}", &archive.to_string_lossy()))?;
+    let file = File::open(&archive).map_err(|e| e.to_string())?;
+
+    return match archive_type {
+        ArchiveType::Tar => extract_tar(file, &destination_folder, &configuration),
+        ArchiveType::TarGz => extract_tar(GzDecoder::new(file), &destination_folder, &configuration),
+        ArchiveType::TarXz => extract_tar_xz(file, &destination_folder, &configuration),
+        ArchiveType::Zip => extract_zip(file, &destination_folder, &configuration)
+    };
+}
+
+fn get_archive_type(archive: &Path) -> Option<ArchiveType> {
+    let name = archive.file_name()?.to_string_lossy().to_lowercase();
+
+    if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        return Some(ArchiveType::TarGz);
+    }
+
+    if name.ends_with(".tar.xz") || name.ends_with(".txz") {
+        return Some(ArchiveType::TarXz);
+    }
+
+    if name.ends_with(".tar") {
+        return Some(ArchiveType::Tar);
+    }
+
+    if name.ends_with(".zip") {
+        return Some(ArchiveType::Zip);
+    }
+
+    return None;
+}
+
+fn extract_tar(
+    reader: impl Read,
+    destination_folder: &Path,
+    configuration: &ExtractConfiguration
+) -> Result<(), String> {
+    let mut archive = tar::Archive::new(reader);
+
+    for entry in archive.entries().map_err(|e| e.to_string())? {
+        let mut entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path().map_err(|e| e.to_string())?.to_path_buf();
+
+        let Some(target) = get_target(&path, &destination_folder, &configuration) else {
+            continue;
+        };
+
+        if has_linked_parent(&target, &destination_folder) {
+            continue;
+        }
+
+        let entry_type = entry.header().entry_type();
+
+        if entry_type.is_symlink() {
+            let link_name = entry.link_name().map_err(|e| e.to_string())?.unwrap_or_default();
+
+            if !is_link_inside(&target, &link_name, &destination_folder) {
+                continue;
+            }
+        }
+
+        create_parent_folder(&target).map_err(|e| e.to_string())?;
+
+        if entry_type.is_hard_link() {
+            let link_name = entry.link_name().map_err(|e| e.to_string())?.unwrap_or_default();
+            extract_hard_link(&target, &link_name, &destination_folder, &configuration)
+                .map_err(|e| e.to_string())?;
+            continue;
+        }
+
+        entry.unpack(&target).map_err(|e| e.to_string())?;
+    }
+
+    return Ok(());
+}
+
+/// The xz decoder only writes its output, so it decompresses the archive on a separate
+/// thread into a pipe which the tar entries are read from while it's being decompressed
+fn extract_tar_xz(
+    file: File,
+    destination_folder: &Path,
+    configuration: &ExtractConfiguration
+) -> Result<(), String> {
+    let (mut reader, mut writer) = io::pipe().map_err(|e| e.to_string())?;
+    let decoder = thread::spawn(move || {
+        return lzma_rs::xz_decompress(&mut BufReader::new(file), &mut writer);
+    });
+
+    // Reading the remainder lets the decoder finish and verify the end of the stream
+    let result = extract_tar(&mut reader, &destination_folder, &configuration)
+        .and_then(|_| io::copy(&mut reader, &mut io::sink()).map_err(|e| e.to_string()));
+    drop(reader);
+
+    let decompressed = decoder.join()
+        .map_err(|_| "Unable to decompress the archive".to_string())?;
+    result?;
+
+    return decompressed.map_err(|e| e.to_string());
+}
+
+/// Hard links are created by hand, because the tar crate resolves their
+/// source relative to the working directory instead of the destination
+///
+/// Links to members which weren't extracted as a regular file are skipped
+fn extract_hard_link(
+    target: &Path,
+    link_name: &Path,
+    destination_folder: &Path,
+    configuration: &ExtractConfiguration
+) -> io::Result<()> {
+    let Some(source) = strip_components(&link_name, &configuration).map(|path| destination_folder.join(path)) else {
+        return Ok(());
+    };
+
+    if has_linked_parent(&source, &destination_folder) || !fs::symlink_metadata(&source).is_ok_and(|m| m.is_file()) {
+        return Ok(());
+    }
+
+    if fs::symlink_metadata(&target).is_ok() {
+        fs::remove_file(&target)?;
+    }
+
+    return fs::hard_link(&source, &target);
+}
+
+/// Check whether a symbolic link at the target resolves to a location
+/// inside the destination folder, absolute link names are never allowed
+fn is_link_inside(target: &Path, link_name: &Path, destination_folder: &Path) -> bool {
+    let Some(parent) = target.parent().and_then(|parent| parent.strip_prefix(&destination_folder).ok()) else {
+        return false;
+    };
+    let mut depth = parent.components().count();
+
+    for component in link_name.components() {
+        match component {
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => continue,
+            Component::ParentDir if depth > 0 => depth -= 1,
+            _ => return false
+        }
+    }
+
+    return true;
+}
+
+/// Check whether one of the folders between the destination folder and the
+/// target is a symbolic link, writing through it could leave the destination
+fn has_linked_parent(target: &Path, destination_folder: &Path) -> bool {
+    return target.ancestors()
+        .skip(1)
+        .take_while(|ancestor| *ancestor != destination_folder)
+        .any(|ancestor| fs::symlink_metadata(&ancestor).is_ok_and(|m| m.file_type().is_symlink()));
+}
+
+fn extract_zip(
+    file: File,
+    destination_folder: &Path,
+    configuration: &ExtractConfiguration
+) -> Result<(), String> {
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(|e| e.to_string())?;
+        let Some(path) = entry.enclosed_name() else {
+            continue;
+        };
+        let Some(target) = get_target(&path, &destination_folder, &configuration) else {
+            continue;
+        };
+
+        if has_linked_parent(&target, &destination_folder) {
+            continue;
+        }
+
+        if entry.is_dir() {
+            fs::create_dir_all(&target).map_err(|e| e.to_string())?;
+            continue;
+        }
+
+        if entry.is_symlink() {
+            let mut link_name = String::default();
+            entry.read_to_string(&mut link_name).map_err(|e| e.to_string())?;
+
+            if is_link_inside(&target, Path::new(&link_name), &destination_folder) {
+                extract_symlink(&target, &link_name).map_err(|e| e.to_string())?;
+            }
+
+            continue;
+        }
+
+        create_parent_folder(&target).map_err(|e| e.to_string())?;
+        let mut output = File::create(&target).map_err(|e| e.to_string())?;
+        io::copy(&mut entry, &mut output).map_err(|e| e.to_string())?;
+
+        #[cfg(unix)]
+        if let Some(mode) = entry.unix_mode() {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&target, fs::Permissions::from_mode(mode))
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    return Ok(());
+}
+
+/// Zip archives store symbolic links as members with the link mode bits set
+/// and the link name as their contents
+#[cfg(unix)]
+fn extract_symlink(target: &Path, link_name: &str) -> io::Result<()> {
+    create_parent_folder(&target)?;
+
+    if fs::symlink_metadata(&target).is_ok() {
+        fs::remove_file(&target)?;
+    }
+
+    return std::os::unix::fs::symlink(&link_name, &target);
+}
+
+/// Symbolic links are skipped on other platforms instead of writing the link name to a file
+#[cfg(not(unix))]
+fn extract_symlink(_: &Path, _: &str) -> io::Result<()> {
+    return Ok(());
+}
+
+/// Get the location of an archive member inside the destination folder
+///
+/// Returns `None` for members which shouldn't be extracted, which are members
+/// that are stripped away completely, aren't selected, or point outside the
+/// destination folder
+fn get_target(path: &Path, destination_folder: &Path, configuration: &ExtractConfiguration) -> Option<PathBuf> {
+    let stripped = strip_components(&path, &configuration)?;
+
+    if !is_selected(&stripped, &configuration) {
+        return None;
+    }
+
+    return Some(destination_folder.join(stripped));
+}
+
+/// Strip the leading components of an archive member
+///
+/// Returns `None` when nothing is left, or when the member isn't a plain
+/// relative path
+fn strip_components(path: &Path, configuration: &ExtractConfiguration) -> Option<PathBuf> {
+    let mut components = Vec::new();
+
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => components.push(name),
+            Component::CurDir => continue,
+            _ => return None
+        }
+    }
+
+    let stripped: PathBuf = components.iter()
+        .skip(configuration.strip_components)
+        .collect();
+
+    if stripped.as_os_str().is_empty() {
+        return None;
+    }
+
+    return Some(stripped);
+}
+
+/// Members are selected when no members are configured, or when they
+/// match one of the configured members or are located inside one of them
+fn is_selected(path: &Path, configuration: &ExtractConfiguration) -> bool {
+    return configuration.members.is_empty() || configuration.members.iter()
+        .any(|member| path.starts_with(member.trim_end_matches('/')));
+}
+
+fn create_parent_folder(path: &Path) -> io::Result<()> {
+    return match path.parent() {
+        Some(parent) => fs::create_dir_all(&parent),
+        None => Ok(())
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::fs::File;
+    use std::io::{Cursor, Write};
+    use std::path::Path;
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+    use crate::configuration::ExtractConfiguration;
+    use crate::file_download::archive::extract_archive;
+    use crate::test_support::{create_test_folder, delete_test_folder};
+
+    const MEMBERS: [(&str, &str); 3] = [
+        ("upset-v1.0.0/bin/upset", "upset binary"),
+        ("upset-v1.0.0/readme.md", "upset readme"),
+        ("upset-v1.0.0/docs/usage.md", "upset usage")
+    ];
+
+    #[test]
+    fn extract_archive_extracts_all_members_of_a_tar_gz_archive() {
+        // Arrange
+        let folder = create_test_folder("archive", "tar-gz");
+        let archive = folder.join("upset.tar.gz");
+        fs::write(&archive, create_tar_gz()).unwrap();
+
+        // Act
+        let result = extract_archive(&archive, &folder, &ExtractConfiguration::default());
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!("upset binary", fs::read_to_string(folder.join("upset-v1.0.0/bin/upset")).unwrap());
+        assert_eq!("upset usage", fs::read_to_string(folder.join("upset-v1.0.0/docs/usage.md")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn extract_archive_extracts_all_members_of_a_tar_xz_archive() {
+        // Arrange
+        let folder = create_test_folder("archive", "tar-xz");
+        let archive = folder.join("upset.tar.xz");
+        let mut compressed = Vec::new();
+        lzma_rs::xz_compress(&mut Cursor::new(create_tar()), &mut compressed).unwrap();
+        fs::write(&archive, compressed).unwrap();
+
+        // Act
+        let result = extract_archive(&archive, &folder, &ExtractConfiguration::default());
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!("upset readme", fs::read_to_string(folder.join("upset-v1.0.0/readme.md")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn extract_archive_extracts_all_members_of_a_zip_archive() {
+        // Arrange
+        let folder = create_test_folder("archive", "zip");
+        let archive = folder.join("upset.zip");
+        write_zip(&archive);
+
+        // Act
+        let result = extract_archive(&archive, &folder, &ExtractConfiguration::default());
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!("upset binary", fs::read_to_string(folder.join("upset-v1.0.0/bin/upset")).unwrap());
+        assert_eq!("upset readme", fs::read_to_string(folder.join("upset-v1.0.0/readme.md")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn extract_archive_strips_the_leading_path_components() {
+        // Arrange
+        let folder = create_test_folder("archive", "strip-components");
+        let archive = folder.join("upset.zip");
+        let configuration = ExtractConfiguration {
+            strip_components: 1,
+            ..Default::default()
+        };
+        write_zip(&archive);
+
+        // Act
+        let result = extract_archive(&archive, &folder, &configuration);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!("upset binary", fs::read_to_string(folder.join("bin/upset")).unwrap());
+        assert!(!folder.join("upset-v1.0.0").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn extract_archive_only_extracts_the_selected_members() {
+        // Arrange
+        let folder = create_test_folder("archive", "members");
+        let archive = folder.join("upset.tgz");
+        let configuration = ExtractConfiguration {
+            strip_components: 1,
+            members: vec!("bin/upset".to_string(), "docs/".to_string())
+        };
+        fs::write(&archive, create_tar_gz()).unwrap();
+
+        // Act
+        let result = extract_archive(&archive, &folder, &configuration);
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(folder.join("bin/upset").exists());
+        assert!(folder.join("docs/usage.md").exists());
+        assert!(!folder.join("readme.md").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn extract_archive_returns_error_for_unsupported_archives() {
+        // Arrange
+        let folder = create_test_folder("archive", "unsupported");
+        let archive = folder.join("upset.rar");
+        fs::write(&archive, "upset").unwrap();
+
+        // Act
+        let result = extract_archive(&archive, &folder, &ExtractConfiguration::default());
+
+        // Assert
+        assert!(result.is_err());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn extract_archive_does_not_write_through_a_symlink_pointing_outside_the_destination() {
+        // Arrange
+        let folder = create_test_folder("archive", "symlink-absolute");
+        let outside = create_test_folder("archive", "symlink-absolute-outside");
+        let archive = folder.join("upset.tar");
+        let mut builder = tar::Builder::new(Vec::new());
+        append_link(&mut builder, tar::EntryType::Symlink, "upset/etc", &outside.to_string_lossy());
+        append_file(&mut builder, "upset/etc/passwd", "root::0:0::/root:/bin/sh");
+        fs::write(&archive, builder.into_inner().unwrap()).unwrap();
+
+        // Act
+        let result = extract_archive(&archive, &folder, &ExtractConfiguration::default());
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(!outside.join("passwd").exists());
+        assert!(!fs::symlink_metadata(folder.join("upset/etc")).unwrap().file_type().is_symlink());
+
+        // Teardown
+        delete_test_folder(&folder);
+        delete_test_folder(&outside);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn extract_archive_only_extracts_symlinks_which_stay_inside_the_destination() {
+        // Arrange
+        let folder = create_test_folder("archive", "symlink-relative");
+        let archive = folder.join("upset.tar");
+        let mut builder = tar::Builder::new(Vec::new());
+        append_file(&mut builder, "upset/bin/upset", "upset binary");
+        append_link(&mut builder, tar::EntryType::Symlink, "upset/latest", "bin/upset");
+        append_link(&mut builder, tar::EntryType::Symlink, "upset/parent", "../..");
+        append_file(&mut builder, "upset/parent/escaped", "escaped");
+        fs::write(&archive, builder.into_inner().unwrap()).unwrap();
+
+        // Act
+        let result = extract_archive(&archive, &folder, &ExtractConfiguration::default());
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!("upset binary", fs::read_to_string(folder.join("upset/latest")).unwrap());
+        assert!(!fs::symlink_metadata(folder.join("upset/parent")).unwrap().file_type().is_symlink());
+        assert!(!folder.parent().unwrap().join("escaped").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn extract_archive_only_extracts_zip_symlinks_which_stay_inside_the_destination() {
+        // Arrange
+        let folder = create_test_folder("archive", "zip-symlink");
+        let archive = folder.join("upset.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&archive).unwrap());
+        writer.start_file("upset/bin/upset", zip::write::SimpleFileOptions::default()).unwrap();
+        writer.write_all(b"upset binary").unwrap();
+        writer.add_symlink("upset/latest", "bin/upset", zip::write::SimpleFileOptions::default()).unwrap();
+        writer.add_symlink("upset/parent", "../..", zip::write::SimpleFileOptions::default()).unwrap();
+        writer.finish().unwrap();
+
+        // Act
+        let result = extract_archive(&archive, &folder, &ExtractConfiguration::default());
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(fs::symlink_metadata(folder.join("upset/latest")).unwrap().file_type().is_symlink());
+        assert_eq!("upset binary", fs::read_to_string(folder.join("upset/latest")).unwrap());
+        assert!(fs::symlink_metadata(folder.join("upset/parent")).is_err());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn extract_archive_resolves_hard_links_inside_the_destination() {
+        // Arrange
+        let folder = create_test_folder("archive", "hard-link");
+        let archive = folder.join("upset.tar");
+        let configuration = ExtractConfiguration {
+            strip_components: 1,
+            ..Default::default()
+        };
+        let mut builder = tar::Builder::new(Vec::new());
+        append_file(&mut builder, "upset/bin/upset", "upset binary");
+        append_link(&mut builder, tar::EntryType::Link, "upset/bin/upset-link", "upset/bin/upset");
+        append_link(&mut builder, tar::EntryType::Link, "upset/passwd", "../../etc/passwd");
+        fs::write(&archive, builder.into_inner().unwrap()).unwrap();
+
+        // Act
+        let result = extract_archive(&archive, &folder, &configuration);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!("upset binary", fs::read_to_string(folder.join("bin/upset-link")).unwrap());
+        assert!(!folder.join("passwd").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn create_tar() -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+
+        for (path, contents) in MEMBERS {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, contents.as_bytes()).unwrap();
+        }
+
+        return builder.into_inner().unwrap();
+    }
+
+    fn append_file(builder: &mut tar::Builder<Vec<u8>>, path: &str, contents: &str) {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, path, contents.as_bytes()).unwrap();
+    }
+
+    fn append_link(builder: &mut tar::Builder<Vec<u8>>, entry_type: tar::EntryType, path: &str, target: &str) {
+        let mut header = tar::Header::new_gnu();
+        header.set_entry_type(entry_type);
+        header.set_size(0);
+        header.set_mode(0o777);
+        builder.append_link(&mut header, path, target).unwrap();
+    }
+
+    fn create_tar_gz() -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&create_tar()).unwrap();
+
+        return encoder.finish().unwrap();
+    }
+
+    fn write_zip(path: &Path) {
+        let mut writer = zip::ZipWriter::new(File::create(&path).unwrap());
+
+        for (path, contents) in MEMBERS {
+            writer.start_file(path, zip::write::SimpleFileOptions::default()).unwrap();
+            writer.write_all(contents.as_bytes()).unwrap();
+        }
+
+        writer.finish().unwrap();
+    }
+}
@@ -0,0 +1,322 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use sha2::{Digest, Sha256, Sha512};
+use crate::configuration::DownloadFileConfiguration;
+use crate::file_download::destination::file_name_from_url;
+
+/// Length of the hexadecimal representation of a SHA-256 digest
+const SHA256_LENGTH: usize = 64;
+
+/// Length of the hexadecimal representation of a SHA-512 digest
+const SHA512_LENGTH: usize = 128;
+
+#[derive(Debug, PartialEq)]
+enum Algorithm {
+    Sha256,
+    Sha512
+}
+
+/// Verify the downloaded file against the configured checksum
+///
+/// Files without a configured checksum are always considered valid
+pub fn verify_checksum(file: &DownloadFileConfiguration, path: &Path) -> Result<(), String> {
+    let Some(expected) = get_expected_checksum(&file, &path)? else {
+        return Ok(());
+    };
+
+    let algorithm = get_algorithm(&expected)
+        .ok_or(format!("Unsupported checksum for {}", &file.url))?;
+    let actual = calculate_checksum(&algorithm, &path)
+        .map_err(|_| format!("Unable to calculate the checksum of {}", &path.to_string_lossy()))?;
+
+    if !actual.eq_ignore_ascii_case(&expected) {
+        return Err(format!("Checksum of {} does not match", &file.url));
+    }
+
+    return Ok(());
+}
+
+/// Check whether the file already exists and matches its configured checksum
+///
+/// Always false for files without a configured checksum, as there's
+/// no way of telling whether the file has changed
+pub fn matches_checksum(file: &DownloadFileConfiguration, path: &Path) -> bool {
+    let has_checksum = file.sha256.is_some() || file.sha512.is_some() || file.checksums_url.is_some();
+
+    return has_checksum && path.is_file() && verify_checksum(&file, &path).is_ok();
+}
+
+fn get_expected_checksum(file: &DownloadFileConfiguration, path: &Path) -> Result<Option<String>, String> {
+    if let Some(sha512) = &file.sha512 {
+        return Ok(Some(sha512.trim().to_string()));
+    }
+
+    if let Some(sha256) = &file.sha256 {
+        return Ok(Some(sha256.trim().to_string()));
+    }
+
+    if let Some(checksums_url) = &file.checksums_url {
+        let checksums = ureq::get(checksums_url)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|_| format!("Unable to download the checksums from {}", &checksums_url))?;
+        let names = [
+            file_name_from_url(&file.url),
+            path.file_name().map(|name| name.to_string_lossy().to_string())
+        ];
+
+        return names.iter()
+            .flatten()
+            .find_map(|name| find_checksum(&checksums, &name))
+            .map(Some)
+            .ok_or(format!("No checksum found for {} in {}", &file.url, &checksums_url));
+    }
+
+    return Ok(None);
+}
+
+/// Find the digest of a file in the contents of a `SHA256SUMS` style file,
+/// where every line contains the digest followed by the name of the file
+fn find_checksum(checksums: &str, name: &str) -> Option<String> {
+    return checksums.lines()
+        .filter_map(|line| line.split_once(char::is_whitespace))
+        .find(|(_, filename)| filename.trim().trim_start_matches('*') == name)
+        .map(|(digest, _)| digest.to_string());
+}
+
+fn get_algorithm(digest: &str) -> Option<Algorithm> {
+    return match digest.len() {
+        SHA256_LENGTH => Some(Algorithm::Sha256),
+        SHA512_LENGTH => Some(Algorithm::Sha512),
+        _ => None
+    };
+}
+
+fn calculate_checksum(algorithm: &Algorithm, path: &Path) -> io::Result<String> {
+    let mut file = File::open(&path)?;
+
+    let digest = match algorithm {
+        Algorithm::Sha256 => {
+            let mut hasher = Sha256::new();
+            io::copy(&mut file, &mut hasher)?;
+            hasher.finalize().to_vec()
+        },
+        Algorithm::Sha512 => {
+            let mut hasher = Sha512::new();
+            io::copy(&mut file, &mut hasher)?;
+            hasher.finalize().to_vec()
+        }
+    };
+
+    return Ok(digest.iter().map(|byte| format!("{:02x}", byte)).collect());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use crate::configuration::DownloadFileConfiguration;
+    use crate::file_download::checksum::{find_checksum, matches_checksum, verify_checksum};
+    use crate::file_download::test_server::{response, serve};
+
+    const UPSET_SHA256: &str = "8b2f406339328a5dd4c6586a2da0d35978b7a9a13bf1bb0c38274e6ba245964a";
+    const UPSET_SHA512: &str = "78b59b6db8f3b49a60b57a925bde2091822e8d1a132236429a1682c8c716087cd7665b4f206670fad09e9bad5a3b07661fe2de17380d07f396c00085a99ccf8f";
+
+    #[test]
+    fn verify_checksum_succeeds_without_a_configured_checksum() {
+        // Arrange
+        let file = DownloadFileConfiguration::from("https://bartkessels.net/upset.zip".to_string());
+        let path = write_test_file("no-checksum");
+
+        // Act
+        let result = verify_checksum(&file, &path);
+
+        // Assert
+        assert!(result.is_ok());
+
+        // Teardown
+        delete_test_file(&path);
+    }
+
+    #[test]
+    fn verify_checksum_succeeds_when_the_sha256_matches() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            sha256: Some(UPSET_SHA256.to_uppercase()),
+            ..Default::default()
+        };
+        let path = write_test_file("sha256");
+
+        // Act
+        let result = verify_checksum(&file, &path);
+
+        // Assert
+        assert!(result.is_ok());
+
+        // Teardown
+        delete_test_file(&path);
+    }
+
+    #[test]
+    fn verify_checksum_succeeds_when_the_sha512_matches() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            sha512: Some(UPSET_SHA512.to_string()),
+            ..Default::default()
+        };
+        let path = write_test_file("sha512");
+
+        // Act
+        let result = verify_checksum(&file, &path);
+
+        // Assert
+        assert!(result.is_ok());
+
+        // Teardown
+        delete_test_file(&path);
+    }
+
+    #[test]
+    fn verify_checksum_returns_error_when_the_checksum_does_not_match() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            sha256: Some(UPSET_SHA256.replace('8', "9")),
+            ..Default::default()
+        };
+        let path = write_test_file("mismatch");
+
+        // Act
+        let result = verify_checksum(&file, &path);
+
+        // Assert
+        assert!(result.is_err());
+
+        // Teardown
+        delete_test_file(&path);
+    }
+
+    #[test]
+    fn verify_checksum_uses_the_checksum_from_the_checksums_url() {
+        // Arrange
+        let checksums = format!("{}  it-depends.zip\n{} *upset.zip\n", UPSET_SHA512, UPSET_SHA256);
+        let (address, _) = serve(move |_| response("200 OK", &[], checksums.as_bytes()));
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            checksums_url: Some(format!("{}/SHA256SUMS", &address)),
+            ..Default::default()
+        };
+        let path = write_test_file("checksums-url");
+
+        // Act
+        let result = verify_checksum(&file, &path);
+
+        // Assert
+        assert!(result.is_ok());
+
+        // Teardown
+        delete_test_file(&path);
+    }
+
+    #[test]
+    fn verify_checksum_returns_error_when_the_file_is_not_in_the_checksums_url() {
+        // Arrange
+        let checksums = format!("{}  it-depends.zip\n", UPSET_SHA256);
+        let (address, _) = serve(move |_| response("200 OK", &[], checksums.as_bytes()));
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            checksums_url: Some(format!("{}/SHA256SUMS", &address)),
+            ..Default::default()
+        };
+        let path = write_test_file("checksums-url-missing");
+
+        // Act
+        let result = verify_checksum(&file, &path);
+
+        // Assert
+        assert!(result.is_err());
+
+        // Teardown
+        delete_test_file(&path);
+    }
+
+    #[test]
+    fn matches_checksum_returns_false_without_a_configured_checksum() {
+        // Arrange
+        let file = DownloadFileConfiguration::from("https://bartkessels.net/upset.zip".to_string());
+        let path = write_test_file("matches-no-checksum");
+
+        // Act
+        let result = matches_checksum(&file, &path);
+
+        // Assert
+        assert!(!result);
+
+        // Teardown
+        delete_test_file(&path);
+    }
+
+    #[test]
+    fn matches_checksum_returns_false_when_the_file_does_not_exist() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            sha256: Some(UPSET_SHA256.to_string()),
+            ..Default::default()
+        };
+        let path = env::temp_dir().join("upset-checksum-does-not-exist");
+
+        // Act
+        let result = matches_checksum(&file, &path);
+
+        // Assert
+        assert!(!result);
+    }
+
+    #[test]
+    fn matches_checksum_returns_true_when_the_file_matches_the_checksum() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            sha256: Some(UPSET_SHA256.to_string()),
+            ..Default::default()
+        };
+        let path = write_test_file("matches");
+
+        // Act
+        let result = matches_checksum(&file, &path);
+
+        // Assert
+        assert!(result);
+
+        // Teardown
+        delete_test_file(&path);
+    }
+
+    #[test]
+    fn find_checksum_returns_none_for_unknown_files() {
+        // Arrange
+        let checksums = format!("{}  upset.zip", UPSET_SHA256);
+
+        // Act
+        let result = find_checksum(&checksums, "it-depends.zip");
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    fn write_test_file(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("upset-checksum-{}", &name));
+        fs::write(&path, "upset").expect("Unable to write the test file");
+
+        return path;
+    }
+
+    fn delete_test_file(path: &PathBuf) {
+        fs::remove_file(&path).expect("Unable to delete the test file");
+    }
+}
@@ -0,0 +1,439 @@
+use std::any::Any;
+use std::path::Path;
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::DownloadFileConfiguration;
+use crate::file_download::download_pipeline::{download_file, FetchResult};
+use crate::file_download::file_download::FileDownload;
+use crate::path::expand_home_directory;
+use crate::terminal::TerminalOutput;
+
+pub struct CurlFileDownload {
+    /// The curl command
+    curl_command: Arc<dyn Command>,
+
+    /// The folder where the files are downloaded to
+    destination_folder: String,
+
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl FileDownload for CurlFileDownload {
+    fn download(&self, files: &Vec<DownloadFileConfiguration>) {
+        for file in files {
+            self.download_file(&file);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl CurlFileDownload {
+    pub fn new(
+        curl_command: &Arc<dyn Command>,
+        destination_folder: &str,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn FileDownload> {
+        return Arc::new(Self {
+            curl_command: curl_command.clone(),
+            destination_folder: expand_home_directory(&destination_folder),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    pub fn download_file(&self, file: &DownloadFileConfiguration) {
+        download_file(&file, &self.destination_folder, &self.terminal_output, |url, destination, resume| {
+            let arguments = get_arguments(&url, &destination, resume);
+
+            return match self.curl_command.execute(&arguments) {
+                Ok(true) => Ok(FetchResult::Downloaded),
+                _ => Err(format!("Unable to download {}", &url))
+            };
+        });
+    }
+}
+
+/// Follow redirects and fail on HTTP errors instead of saving the error page
+///
+/// Only retries continue the partially downloaded file, because that file
+/// was left by the failed attempt before it. The first attempt starts over,
+/// so a file which has grown since the previous download isn't appended to.
+fn get_arguments(url: &str, destination: &Path, resume: bool) -> Vec<String> {
+    let mut arguments = vec!(
+        "--fail".to_string(),
+        "--location".to_string()
+    );
+
+    if resume {
+        arguments.extend(["--continue-at".to_string(), "-".to_string()]);
+    }
+
+    arguments.extend([
+        "--create-dirs".to_string(),
+        "--output".to_string(),
+        destination.to_string_lossy().to_string(),
+        url.to_string()
+    ]);
+
+    return arguments;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::sync::Arc;
+    use mockall::Sequence;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::DownloadFileConfiguration;
+    use crate::file_download::curl_file_download::CurlFileDownload;
+    use crate::path::expand_home_directory;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output_mock;
+
+    #[test]
+    fn download_calls_the_curl_command_for_each_file() {
+        // Arrange
+        let remote_sources = vec!(
+            DownloadFileConfiguration::from("https://bartkessels.net/remote1".to_string()),
+            DownloadFileConfiguration::from("https://bartkessels.net/remote2".to_string())
+        );
+        let destination_folder = "~/Downloads";
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectations
+        command_mock.expect_execute()
+            .times(remote_sources.len())
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_calls_the_curl_command_with_the_expected_arguments() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
+        let destination_folder = "/tmp/Downloads";
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &[
+                "--fail".to_string(),
+                "--location".to_string(),
+                "--create-dirs".to_string(),
+                "--output".to_string(),
+                "/tmp/Downloads/release.zip".to_string(),
+                remote_source.to_string()
+            ])
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_calls_the_curl_command_with_the_output_file_when_a_filename_is_set() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: remote_source.to_string(),
+            filename: Some("upset.zip".to_string()),
+            ..Default::default()
+        });
+        let destination_folder = "/tmp/Downloads";
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args.contains(&"/tmp/Downloads/upset.zip".to_string()))
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_calls_the_curl_command_with_the_home_directory_expanded() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
+        let destination_folder = "~/Downloads";
+        let expected_destination = format!("{}/release.zip", expand_home_directory(&destination_folder));
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(move |args| args.contains(&expected_destination))
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_calls_loading_on_the_the_terminal_output_before_the_command_is_being_executed() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
+        let destination_folder = "~/Downloads";
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the mocks
+        let mut sequence = Sequence::new();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+        terminal_output_mock.expect_finish_with_success().returning(|_| {});
+
+        command_mock.expect_execute()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_calls_the_finish_with_success_on_the_the_terminal_output_when_the_command_succeeded() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
+        let destination_folder = "~/Downloads";
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the mocks
+        command_mock.expect_execute().returning(|_| Ok(true));
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_calls_finish_with_warning_on_the_the_terminal_output_when_the_command_fails() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
+        let destination_folder = "~/Downloads";
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the mocks
+        command_mock.expect_execute().returning(|_| Ok(false));
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_calls_finish_with_warning_on_the_the_terminal_output_when_the_command_returns_an_error() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
+        let destination_folder = "~/Downloads";
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the mocks
+        command_mock.expect_execute().returning(|_| Err(String::default()));
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_removes_the_file_and_calls_finish_with_warning_when_the_checksum_does_not_match() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: remote_source.to_string(),
+            sha256: Some("0".repeat(64)),
+            ..Default::default()
+        });
+        let destination_folder = env::temp_dir().join("upset-curl-checksum-mismatch");
+        let destination = destination_folder.join("release.zip");
+        let downloaded_file = destination.clone();
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        fs::create_dir_all(&destination_folder).expect("Unable to create the destination folder");
+
+        // Setup the mocks
+        command_mock.expect_execute().returning(move |_| {
+            fs::write(&downloaded_file, "upset").expect("Unable to write the downloaded file");
+            Ok(true)
+        });
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+
+        // Assert
+        assert!(!destination.exists());
+
+        // Teardown
+        fs::remove_dir_all(&destination_folder).expect("Unable to delete the destination folder");
+    }
+
+    #[test]
+    fn download_retries_the_curl_command_with_the_mirror_when_it_fails() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let mirror = "https://mirror.bartkessels.net/upset/latest";
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: remote_source.to_string(),
+            mirrors: vec!(mirror.to_string()),
+            retries: 1,
+            retry_delay: Some(0),
+            ..Default::default()
+        });
+        let destination_folder = "/tmp/Downloads";
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectations
+        let mut sequence = Sequence::new();
+
+        command_mock.expect_execute()
+            .withf(move |args| args.last().unwrap() == remote_source && !args.contains(&"--continue-at".to_string()))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(false));
+        command_mock.expect_execute()
+            .withf(move |args| args.last().unwrap() == remote_source && args.contains(&"--continue-at".to_string()))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(false));
+        command_mock.expect_execute()
+            .withf(move |args| args.last().unwrap() == mirror && args.contains(&"/tmp/Downloads/release.zip".to_string()))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_does_not_call_the_curl_command_when_the_file_matches_the_checksum() {
+        // Arrange
+        let destination_folder = env::temp_dir().join("upset-curl-up-to-date");
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: "https://github.com/bartkessels/upset/release.zip".to_string(),
+            sha256: Some("8b2f406339328a5dd4c6586a2da0d35978b7a9a13bf1bb0c38274e6ba245964a".to_string()),
+            ..Default::default()
+        });
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        fs::create_dir_all(&destination_folder).expect("Unable to create the destination folder");
+        fs::write(destination_folder.join("release.zip"), "upset").expect("Unable to write the downloaded file");
+
+        // Setup the expectations
+        command_mock.expect_execute()
+            .never()
+            .returning(|_| Ok(true));
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CurlFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+
+        // Teardown
+        fs::remove_dir_all(&destination_folder).expect("Unable to delete the destination folder");
+    }
+}
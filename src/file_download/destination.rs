@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+use crate::configuration::DownloadFileConfiguration;
+
+/// Get the location of the file inside the destination folder
+///
+/// Uses the configured file name when available, otherwise
+/// the name of the file in the URL
+pub fn get_destination(destination_folder: &str, file: &DownloadFileConfiguration) -> Result<PathBuf, String> {
+    let filename = match &file.filename {
+        Some(filename) => filename.to_string(),
+        None => file_name_from_url(&file.url)
+            .ok_or(format!("Unable to determine the file name of {}", &file.url))?
+    };
+
+    return Ok(Path::new(&destination_folder).join(filename));
+}
+
+/// Get the last path segment of the URL, without the query or fragment
+pub fn file_name_from_url(url: &str) -> Option<String> {
+    let without_query = url.split(['?', '#']).next().unwrap_or_default();
+    let without_scheme = without_query.split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(without_query);
+
+    return without_scheme.split_once('/')
+        .and_then(|(_, path)| path.rsplit('/').next())
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use crate::configuration::DownloadFileConfiguration;
+    use crate::file_download::destination::{file_name_from_url, get_destination};
+
+    #[test]
+    fn get_destination_uses_the_name_from_the_url() {
+        // Arrange
+        let file = DownloadFileConfiguration::from("https://github.com/bartkessels/upset/release.zip".to_string());
+
+        // Act
+        let result = get_destination("/tmp/Downloads", &file);
+
+        // Assert
+        assert_eq!(Ok(Path::new("/tmp/Downloads/release.zip").to_path_buf()), result);
+    }
+
+    #[test]
+    fn get_destination_uses_the_configured_filename() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://github.com/bartkessels/upset/release.zip".to_string(),
+            filename: Some("upset.zip".to_string()),
+            ..Default::default()
+        };
+
+        // Act
+        let result = get_destination("/tmp/Downloads", &file);
+
+        // Assert
+        assert_eq!(Ok(Path::new("/tmp/Downloads/upset.zip").to_path_buf()), result);
+    }
+
+    #[test]
+    fn file_name_from_url_returns_the_last_path_segment_without_the_query() {
+        // Arrange
+        let url = "https://github.com/bartkessels/upset/release.zip?version=1#top";
+
+        // Act
+        let result = file_name_from_url(&url);
+
+        // Assert
+        assert_eq!(Some("release.zip".to_string()), result);
+    }
+
+    #[test]
+    fn file_name_from_url_returns_none_when_the_url_has_no_path() {
+        // Arrange
+        let url = "https://github.com/";
+
+        // Act
+        let result = file_name_from_url(&url);
+
+        // Assert
+        assert!(result.is_none());
+    }
+}
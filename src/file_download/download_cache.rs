@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Name of the cache file inside the destination folder
+const CACHE_FILE_NAME: &str = ".upset-downloads.yaml";
+
+/// Validators of a downloaded file which are used to
+/// ask the server whether the file has changed since
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CacheEntry {
+    /// The URL the file was downloaded from
+    pub url: String,
+
+    /// The `ETag` header of the response
+    pub etag: Option<String>,
+
+    /// The `Last-Modified` header of the response
+    pub last_modified: Option<String>
+}
+
+/// Cache of the downloaded files in a destination folder, stored
+/// as a YAML file which maps the file names to their validators
+pub struct DownloadCache {
+    path: PathBuf
+}
+
+impl DownloadCache {
+    pub fn new(destination_folder: &str) -> Self {
+        return Self {
+            path: Path::new(&destination_folder).join(CACHE_FILE_NAME)
+        };
+    }
+
+    /// Get the validators of the file, if it has been downloaded before
+    pub fn get(&self, filename: &str) -> Option<CacheEntry> {
+        return self.read().remove(filename);
+    }
+
+    /// Store the validators of the file, files without any
+    /// validators are removed from the cache instead
+    pub fn set(&self, filename: &str, entry: CacheEntry) -> Result<(), String> {
+        let mut entries = self.read();
+
+        if entry.etag.is_none() && entry.last_modified.is_none() {
+            if entries.remove(filename).is_none() {
+                return Ok(());
+            }
+        } else {
+            entries.insert(filename.to_string(), entry);
+        }
+
+        let file = File::create(&self.path).map_err(|e| e.to_string())?;
+
+        return serde_yaml::to_writer(file, &entries).map_err(|e| e.to_string());
+    }
+
+    fn read(&self) -> BTreeMap<String, CacheEntry> {
+        return fs::read_to_string(&self.path).ok()
+            .and_then(|contents| serde_yaml::from_str(&contents).ok())
+            .unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::file_download::download_cache::{CacheEntry, DownloadCache};
+    use crate::test_support::{create_test_folder, delete_test_folder};
+
+    #[test]
+    fn get_returns_none_when_there_is_no_cache() {
+        // Arrange
+        let folder = create_test_folder("download-cache", "no-cache");
+
+        // Act
+        let sut = DownloadCache::new(&folder.to_string_lossy());
+        let result = sut.get("upset.zip");
+
+        // Assert
+        assert!(result.is_none());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn get_returns_the_entry_that_has_been_set() {
+        // Arrange
+        let folder = create_test_folder("download-cache", "set");
+        let entry = CacheEntry {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None
+        };
+
+        // Act
+        let sut = DownloadCache::new(&folder.to_string_lossy());
+        sut.set("upset.zip", entry.clone()).unwrap();
+        sut.set("it-depends.zip", CacheEntry {
+            url: "https://bartkessels.net/it-depends.zip".to_string(),
+            etag: None,
+            last_modified: Some("Mon, 01 Jan 2024 00:00:00 GMT".to_string())
+        }).unwrap();
+        let result = sut.get("upset.zip");
+
+        // Assert
+        assert_eq!(Some(entry), result);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn set_removes_the_entry_when_there_are_no_validators() {
+        // Arrange
+        let folder = create_test_folder("download-cache", "remove");
+        let url = "https://bartkessels.net/upset.zip".to_string();
+
+        // Act
+        let sut = DownloadCache::new(&folder.to_string_lossy());
+        sut.set("upset.zip", CacheEntry {
+            url: url.clone(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None
+        }).unwrap();
+        sut.set("upset.zip", CacheEntry { url, ..Default::default() }).unwrap();
+        let result = sut.get("upset.zip");
+
+        // Assert
+        assert!(result.is_none());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+}
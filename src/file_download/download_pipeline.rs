@@ -0,0 +1,191 @@
+use std::path::Path;
+use std::sync::Arc;
+use crate::configuration::DownloadFileConfiguration;
+use crate::file_download::checksum::matches_checksum;
+use crate::file_download::destination::get_destination;
+use crate::file_download::github_release::resolve_github_release;
+use crate::file_download::post_download::{process_downloaded_file, verify_downloaded_file};
+use crate::file_download::retry::download_with_retries;
+use crate::terminal::TerminalOutput;
+
+/// Result of a single attempt to download a file
+pub enum FetchResult {
+    /// The file has been written to its destination
+    Downloaded,
+
+    /// The server reported that the file hasn't changed since the previous download
+    NotModified
+}
+
+/// Download the file using the fetch function of a download manager
+///
+/// Takes care of everything that's the same for every download manager, it resolves GitHub
+/// releases, skips files which match their checksum, retries the download and its mirrors
+/// and verifies and processes the file once it has been downloaded.
+///
+/// The fetch function receives the URL of the attempt, the destination of the file and
+/// whether the attempt follows a failed one, in which case the partially downloaded file
+/// of that attempt can be continued.
+pub fn download_file<F>(
+    file: &DownloadFileConfiguration,
+    destination_folder: &str,
+    terminal_output: &Arc<dyn TerminalOutput>,
+    mut fetch: F
+)
+where
+    F: FnMut(&str, &Path, bool) -> Result<FetchResult, String>
+{
+    let file = match resolve_github_release(&file) {
+        Ok(file) => file,
+        Err(error) => {
+            terminal_output.finish_with_warning(&error);
+            return;
+        }
+    };
+
+    let Ok(destination) = get_destination(&destination_folder, &file) else {
+        terminal_output.finish_with_warning(
+            &format!("Unable to download {}", &file.url)
+        );
+        return;
+    };
+
+    if matches_checksum(&file, &destination) {
+        terminal_output.finish_with_success(&format!("{} is up to date", &file.url));
+        return;
+    }
+
+    let mut resume = false;
+    let result = download_with_retries(&file, |url| {
+        terminal_output.loading(&format!("Downloading {}", &url));
+
+        let result = fetch(&url, &destination, resume);
+        resume = true;
+
+        return result;
+    });
+
+    let result = match result {
+        Ok(FetchResult::Downloaded) => process_downloaded_file(&file, &destination),
+        Ok(FetchResult::NotModified) => match verify_downloaded_file(&file, &destination) {
+            Ok(()) => {
+                terminal_output.finish_with_success(&format!("{} is up to date", &file.url));
+                return;
+            },
+            Err(error) => Err(error)
+        },
+        Err(_) => Err(format!("Unable to download {}", &file.url))
+    };
+
+    match result {
+        Ok(()) => terminal_output.finish_with_success(
+            &format!("Successfully downloaded {}", &file.url)
+        ),
+        Err(error) => terminal_output.finish_with_warning(&error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use crate::configuration::DownloadFileConfiguration;
+    use crate::file_download::download_pipeline::{download_file, FetchResult};
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder};
+
+    #[test]
+    fn download_file_passes_the_destination_and_whether_the_attempt_is_a_retry() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            retries: 2,
+            retry_delay: Some(0),
+            ..Default::default()
+        };
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        let mut attempts = Vec::new();
+
+        // Setup the expectations
+        terminal_output_mock.expect_loading()
+            .times(3)
+            .returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.starts_with("Successfully downloaded"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        download_file(&file, "/tmp/Downloads", &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>), |_, destination, resume| {
+            attempts.push((destination.to_path_buf(), resume));
+
+            return match attempts.len() {
+                3 => Ok(FetchResult::Downloaded),
+                _ => Err("Unable to download".to_string())
+            };
+        });
+
+        // Assert
+        let destination = PathBuf::from("/tmp/Downloads/upset.zip");
+        assert_eq!(vec!(
+            (destination.to_path_buf(), false),
+            (destination.to_path_buf(), true),
+            (destination.to_path_buf(), true)
+        ), attempts);
+    }
+
+    #[test]
+    fn download_file_does_not_fetch_the_file_when_it_matches_the_checksum() {
+        // Arrange
+        let destination_folder = create_test_folder("download-pipeline", "up-to-date");
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            sha256: Some("8b2f406339328a5dd4c6586a2da0d35978b7a9a13bf1bb0c38274e6ba245964a".to_string()),
+            ..Default::default()
+        };
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        let mut attempts = 0;
+        fs::write(destination_folder.join("upset.zip"), "upset").unwrap();
+
+        // Setup the expectations
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        download_file(&file, &destination_folder.to_string_lossy(), &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>), |_, _, _| {
+            attempts += 1;
+            return Ok(FetchResult::Downloaded);
+        });
+
+        // Assert
+        assert_eq!(0, attempts);
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_file_calls_finish_with_warning_when_the_destination_can_not_be_determined() {
+        // Arrange
+        let file = DownloadFileConfiguration::from("https://bartkessels.net/".to_string());
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        let mut attempts = 0;
+
+        // Setup the expectations
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        download_file(&file, "/tmp/Downloads", &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>), |_, _, _| {
+            attempts += 1;
+            return Ok(FetchResult::Downloaded);
+        });
+
+        // Assert
+        assert_eq!(0, attempts);
+    }
+}
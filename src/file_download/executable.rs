@@ -0,0 +1,160 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::path::{create_symlink, expand_home_directory, resolve_destination};
+
+/// Allow the file to be executed by everyone who is able to read it
+#[cfg(unix)]
+pub fn make_executable(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(&path)?.permissions();
+    let mode = permissions.mode();
+    permissions.set_mode(mode | ((mode & 0o444) >> 2));
+
+    return fs::set_permissions(&path, permissions);
+}
+
+/// Files are executable based on their extension on Windows
+#[cfg(not(unix))]
+pub fn make_executable(_: &Path) -> io::Result<()> {
+    return Ok(());
+}
+
+/// Create a symbolic link to the file
+///
+/// When `link_to` is a folder, or ends with a path separator, the link is created
+/// inside of it using the name of the file. Existing links are replaced, but other
+/// files are never overwritten.
+pub fn create_link(path: &Path, link_to: &str) -> io::Result<PathBuf> {
+    let target = fs::canonicalize(&path)?;
+    let link = resolve_destination(&expand_home_directory(&link_to), &target);
+
+    if let Some(parent) = link.parent() {
+        fs::create_dir_all(&parent)?;
+    }
+
+    if let Ok(metadata) = fs::symlink_metadata(&link) {
+        if !metadata.file_type().is_symlink() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", &link.to_string_lossy())
+            ));
+        }
+
+        fs::remove_file(&link)?;
+    }
+
+    create_symlink(&target, &link)?;
+
+    return Ok(link);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::file_download::executable::{create_link, make_executable};
+    use crate::test_support::{create_test_folder, delete_test_folder};
+
+    #[test]
+    #[cfg(unix)]
+    fn make_executable_adds_the_execute_permission_for_everyone_who_can_read_the_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange
+        let folder = create_test_folder("executable", "executable");
+        let path = folder.join("kubectl");
+        fs::write(&path, "kubectl").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+        // Act
+        let result = make_executable(&path);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(0o750, fs::metadata(&path).unwrap().permissions().mode() & 0o777);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn create_link_creates_the_link_inside_the_folder_using_the_file_name() {
+        // Arrange
+        let folder = create_test_folder("executable", "link-folder");
+        let path = folder.join("kubectl");
+        let link_folder = folder.join("bin");
+        fs::write(&path, "kubectl").unwrap();
+
+        // Act
+        let result = create_link(&path, &format!("{}/", link_folder.to_string_lossy()));
+
+        // Assert
+        assert_eq!(link_folder.join("kubectl"), result.unwrap());
+        assert_eq!("kubectl", fs::read_to_string(link_folder.join("kubectl")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn create_link_creates_the_link_at_the_given_path() {
+        // Arrange
+        let folder = create_test_folder("executable", "link-path");
+        let path = folder.join("kubectl-v1.28.0");
+        let link = folder.join("bin").join("kubectl");
+        fs::write(&path, "kubectl").unwrap();
+
+        // Act
+        let result = create_link(&path, &link.to_string_lossy());
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(fs::symlink_metadata(&link).unwrap().file_type().is_symlink());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn create_link_replaces_an_existing_link() {
+        // Arrange
+        let folder = create_test_folder("executable", "link-replace");
+        let old_path = folder.join("kubectl-v1.27.0");
+        let path = folder.join("kubectl-v1.28.0");
+        let link = folder.join("kubectl");
+        fs::write(&old_path, "kubectl v1.27.0").unwrap();
+        fs::write(&path, "kubectl v1.28.0").unwrap();
+        create_link(&old_path, &link.to_string_lossy()).unwrap();
+
+        // Act
+        let result = create_link(&path, &link.to_string_lossy());
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!("kubectl v1.28.0", fs::read_to_string(&link).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn create_link_returns_error_when_a_file_exists_at_the_link() {
+        // Arrange
+        let folder = create_test_folder("executable", "link-existing-file");
+        let path = folder.join("kubectl-v1.28.0");
+        let link = folder.join("kubectl");
+        fs::write(&path, "kubectl v1.28.0").unwrap();
+        fs::write(&link, "my own kubectl").unwrap();
+
+        // Act
+        let result = create_link(&path, &link.to_string_lossy());
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!("my own kubectl", fs::read_to_string(&link).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+}
@@ -1,9 +1,10 @@
 use std::any::Any;
 use mockall::automock;
+use crate::configuration::DownloadFileConfiguration;
 
 /// Public methods to call on a specific download manager
 #[automock]
 pub trait FileDownload {
-    fn download(&self, remote_source: &Vec<String>);
+    fn download(&self, files: &Vec<DownloadFileConfiguration>);
     fn as_any(&self) -> &dyn Any;
-}
\ No newline at end of file
+}
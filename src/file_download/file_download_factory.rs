@@ -1,6 +1,8 @@
 use std::sync::Arc;
-use crate::commands::WgetCommand;
+use crate::commands::{CurlCommand, WgetCommand};
+use crate::file_download::curl_file_download::CurlFileDownload;
 use crate::file_download::file_download::FileDownload;
+use crate::file_download::http_file_download::HttpFileDownload;
 use crate::file_download::wget_file_download::WgetFileDownload;
 use crate::terminal::TerminalOutput;
 
@@ -22,11 +24,20 @@ impl FileDownloadFactory for FileDownloadFactoryImpl {
         destination_folder: &str
     ) -> Option<Arc<dyn FileDownload>> {
         return match name.to_lowercase().as_str() {
+            "http" => Some(HttpFileDownload::new(
+                &destination_folder,
+                &self.terminal_output
+            )),
             "wget" => Some(WgetFileDownload::new(
                 &WgetCommand::new(),
                 &destination_folder,
                 &self.terminal_output
             )),
+            "curl" => Some(CurlFileDownload::new(
+                &CurlCommand::new(),
+                &destination_folder,
+                &self.terminal_output
+            )),
             _ => None
         };
     }
@@ -43,7 +54,9 @@ impl FileDownloadFactoryImpl {
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
+    use crate::file_download::curl_file_download::CurlFileDownload;
     use crate::file_download::file_download_factory::FileDownloadFactoryImpl;
+    use crate::file_download::http_file_download::HttpFileDownload;
     use crate::file_download::wget_file_download::WgetFileDownload;
     use crate::terminal::{MockTerminalOutput, TerminalOutput};
 
@@ -98,4 +111,40 @@ mod tests {
         assert!(result.is_some());
         assert!(result.unwrap().as_any().is::<WgetFileDownload>());
     }
+
+    #[test]
+    fn get_file_download_returns_http_for_http_file_downloader() {
+        // Arrange
+        let name = "http";
+        let destination_folder = ".";
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = FileDownloadFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_file_downloader(name, destination_folder);
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<HttpFileDownload>());
+    }
+
+    #[test]
+    fn get_file_download_returns_curl_for_curl_file_downloader() {
+        // Arrange
+        let name = "curl";
+        let destination_folder = ".";
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = FileDownloadFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_file_downloader(name, destination_folder);
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<CurlFileDownload>());
+    }
 }
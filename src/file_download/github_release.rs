@@ -0,0 +1,224 @@
+use std::borrow::Cow;
+use std::env;
+use crate::configuration::{DownloadFileConfiguration, GithubReleaseConfiguration};
+
+#[derive(serde::Deserialize)]
+struct Release {
+    assets: Vec<Asset>
+}
+
+#[derive(serde::Deserialize)]
+struct Asset {
+    name: String,
+    browser_download_url: String
+}
+
+/// Get the file with the URL of the GitHub release asset filled in
+///
+/// Files which aren't downloaded from a GitHub release are returned as is
+pub fn resolve_github_release(file: &DownloadFileConfiguration) -> Result<Cow<'_, DownloadFileConfiguration>, String> {
+    let Some(release) = &file.github_release else {
+        return Ok(Cow::Borrowed(file));
+    };
+
+    let mut resolved_file = file.clone();
+    resolved_file.url = get_asset_url(&release)?;
+
+    return Ok(Cow::Owned(resolved_file));
+}
+
+fn get_asset_url(release: &GithubReleaseConfiguration) -> Result<String, String> {
+    let release_url = match &release.tag {
+        Some(tag) => format!("{}/repos/{}/releases/tags/{}", &release.api_url, &release.repository, &tag),
+        None => format!("{}/repos/{}/releases/latest", &release.api_url, &release.repository)
+    };
+    let mut request = ureq::get(&release_url)
+        .header("Accept", "application/vnd.github+json");
+
+    if let Ok(token) = env::var("GITHUB_TOKEN") {
+        request = request.header("Authorization", &format!("Bearer {}", &token));
+    }
+
+    let response: Release = request.call()
+        .and_then(|mut response| response.body_mut().read_to_string())
+        .ok()
+        .and_then(|body| serde_json::from_str(&body).ok())
+        .ok_or(format!("Unable to retrieve the release of {}", &release.repository))?;
+    let pattern = fill_placeholders(&release.asset);
+
+    return response.assets.into_iter()
+        .find(|asset| matches_pattern(&asset.name, &pattern))
+        .map(|asset| asset.browser_download_url)
+        .ok_or(format!("No asset matching {} found in the release of {}", &pattern, &release.repository));
+}
+
+/// Replace the `{os}` and `{arch}` placeholders with the names
+/// commonly used in release assets for the current machine
+fn fill_placeholders(asset: &str) -> String {
+    return asset
+        .replace("{os}", get_os_name())
+        .replace("{arch}", get_arch_name());
+}
+
+fn get_os_name() -> &'static str {
+    return match env::consts::OS {
+        "macos" => "darwin",
+        os => os
+    };
+}
+
+fn get_arch_name() -> &'static str {
+    return match env::consts::ARCH {
+        "x86_64" => "amd64",
+        "aarch64" => "arm64",
+        "x86" => "386",
+        arch => arch
+    };
+}
+
+/// Match the name against a pattern where `*` matches any sequence of characters
+fn matches_pattern(name: &str, pattern: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+
+    let Some(mut remainder) = name.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+
+    for (index, part) in parts.iter().enumerate() {
+        if index == parts.len() - 1 {
+            return remainder.ends_with(part);
+        }
+
+        match remainder.find(part) {
+            Some(position) => remainder = &remainder[position + part.len()..],
+            None => return false
+        }
+    }
+
+    return remainder.is_empty();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use crate::configuration::{DownloadFileConfiguration, GithubReleaseConfiguration};
+    use crate::file_download::github_release::{fill_placeholders, get_arch_name, get_os_name, matches_pattern, resolve_github_release};
+    use crate::file_download::test_server::{request_path, response, serve};
+
+    const RELEASE: &str = r#"{
+        "tag_name": "v1.0.0",
+        "assets": [
+            { "name": "upset-linux-amd64.tar.gz", "browser_download_url": "https://github.com/bartkessels/upset/releases/download/v1.0.0/upset-linux-amd64.tar.gz" },
+            { "name": "upset-darwin-arm64.tar.gz", "browser_download_url": "https://github.com/bartkessels/upset/releases/download/v1.0.0/upset-darwin-arm64.tar.gz" }
+        ]
+    }"#;
+
+    #[test]
+    fn resolve_github_release_returns_the_file_when_it_is_not_a_github_release() {
+        // Arrange
+        let file = DownloadFileConfiguration::from("https://bartkessels.net/upset.zip".to_string());
+
+        // Act
+        let result = resolve_github_release(&file);
+
+        // Assert
+        assert!(matches!(result, Ok(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn resolve_github_release_uses_the_matching_asset_of_the_latest_release() {
+        // Arrange
+        let (address, requests) = serve(|_| response("200 OK", &[], RELEASE.as_bytes()));
+        let file = create_github_release_file(&address, None, "upset-linux-*.tar.gz");
+
+        // Act
+        let result = resolve_github_release(&file);
+
+        // Assert
+        assert_eq!(
+            "https://github.com/bartkessels/upset/releases/download/v1.0.0/upset-linux-amd64.tar.gz",
+            result.unwrap().url
+        );
+        assert_eq!("/repos/bartkessels/upset/releases/latest", request_path(&requests.recv().unwrap()));
+    }
+
+    #[test]
+    fn resolve_github_release_uses_the_release_of_the_configured_tag() {
+        // Arrange
+        let (address, requests) = serve(|_| response("200 OK", &[], RELEASE.as_bytes()));
+        let file = create_github_release_file(&address, Some("v1.0.0"), "upset-darwin-arm64.tar.gz");
+
+        // Act
+        let result = resolve_github_release(&file);
+
+        // Assert
+        assert_eq!(
+            "https://github.com/bartkessels/upset/releases/download/v1.0.0/upset-darwin-arm64.tar.gz",
+            result.unwrap().url
+        );
+        assert_eq!("/repos/bartkessels/upset/releases/tags/v1.0.0", request_path(&requests.recv().unwrap()));
+    }
+
+    #[test]
+    fn resolve_github_release_returns_error_when_no_asset_matches() {
+        // Arrange
+        let (address, _) = serve(|_| response("200 OK", &[], RELEASE.as_bytes()));
+        let file = create_github_release_file(&address, None, "upset-windows-*.zip");
+
+        // Act
+        let result = resolve_github_release(&file);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolve_github_release_returns_error_when_the_release_does_not_exist() {
+        // Arrange
+        let (address, _) = serve(|_| response("404 Not Found", &[], b"{}"));
+        let file = create_github_release_file(&address, Some("v0.0.0"), "upset-*");
+
+        // Act
+        let result = resolve_github_release(&file);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fill_placeholders_replaces_the_os_and_arch() {
+        // Arrange
+        let asset = "upset-{os}-{arch}.tar.gz";
+
+        // Act
+        let result = fill_placeholders(&asset);
+
+        // Assert
+        assert_eq!(format!("upset-{}-{}.tar.gz", get_os_name(), get_arch_name()), result);
+    }
+
+    #[test]
+    fn matches_pattern_supports_wildcards() {
+        assert!(matches_pattern("upset-linux-amd64.tar.gz", "upset-linux-amd64.tar.gz"));
+        assert!(matches_pattern("upset-linux-amd64.tar.gz", "upset-*-amd64.tar.gz"));
+        assert!(matches_pattern("upset-linux-amd64.tar.gz", "*.tar.gz"));
+        assert!(matches_pattern("upset-linux-amd64.tar.gz", "upset-*"));
+        assert!(!matches_pattern("upset-linux-amd64.tar.gz", "upset-*.zip"));
+        assert!(!matches_pattern("upset-linux-amd64.tar.gz", "upset-linux"));
+        assert!(!matches_pattern("upset-linux-amd64.tar.gz.sha256", "upset-*.tar.gz"));
+    }
+
+    fn create_github_release_file(api_url: &str, tag: Option<&str>, asset: &str) -> DownloadFileConfiguration {
+        return DownloadFileConfiguration {
+            github_release: Some(GithubReleaseConfiguration {
+                repository: "bartkessels/upset".to_string(),
+                tag: tag.map(|tag| tag.to_string()),
+                asset: asset.to_string(),
+                api_url: api_url.to_string()
+            }),
+            ..Default::default()
+        };
+    }
+}
@@ -0,0 +1,854 @@
+use std::any::Any;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use ureq::Agent;
+use ureq::http::Response;
+use crate::configuration::DownloadFileConfiguration;
+use crate::file_download::download_cache::{CacheEntry, DownloadCache};
+use crate::file_download::download_pipeline::{download_file, FetchResult};
+use crate::file_download::file_download::FileDownload;
+use crate::path::expand_home_directory;
+use crate::terminal::TerminalOutput;
+
+/// Maximum number of redirects that are followed before giving up
+const MAX_REDIRECTS: u32 = 10;
+
+/// Time to wait for the connection to the server to be established
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Time to wait for the server to respond once the request has been sent
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Time to wait for the complete body of the response, downloads which take
+/// longer are continued from their `.part` file by the next attempt
+const BODY_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Size of the chunks in which the response body is written to disk
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Status code of a response containing the requested range of the file
+const PARTIAL_CONTENT: u16 = 206;
+
+/// Status code of a response when the requested range is past the end of the file
+const RANGE_NOT_SATISFIABLE: u16 = 416;
+
+/// Status code of a response when the file hasn't changed since the previous download
+const NOT_MODIFIED: u16 = 304;
+
+/// Native download manager which doesn't depend on any external tools
+pub struct HttpFileDownload {
+    /// The HTTP client used to download the files
+    agent: Agent,
+
+    /// The folder where the files are downloaded to
+    destination_folder: String,
+
+    /// Terminal output where the download status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl FileDownload for HttpFileDownload {
+    fn download(&self, files: &Vec<DownloadFileConfiguration>) {
+        for file in files {
+            self.download_file(&file);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl HttpFileDownload {
+    pub fn new(
+        destination_folder: &str,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn FileDownload> {
+        let agent = Agent::config_builder()
+            .max_redirects(MAX_REDIRECTS)
+            .timeout_connect(Some(CONNECT_TIMEOUT))
+            .timeout_recv_response(Some(RESPONSE_TIMEOUT))
+            .timeout_recv_body(Some(BODY_TIMEOUT))
+            .build()
+            .into();
+
+        return Arc::new(Self {
+            agent,
+            destination_folder: expand_home_directory(&destination_folder),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    pub fn download_file(&self, file: &DownloadFileConfiguration) {
+        download_file(&file, &self.destination_folder, &self.terminal_output, |url, destination, _| {
+            return self.fetch(&url, &destination);
+        });
+    }
+
+    /// Download the file into a temporary `.part` file which is moved
+    /// to its final location once the complete body has been received
+    ///
+    /// When a `.part` file from an earlier attempt exists, only the remaining
+    /// part of the file is requested. The `.part` file is kept when the download
+    /// fails so the next attempt can continue where this one stopped. Its `ETag` or
+    /// `Last-Modified` header is stored in the download cache and sent as `If-Range`,
+    /// so the server sends the complete file when it has changed in the meantime.
+    /// A `.part` file without a stored validator can't be checked and is removed.
+    ///
+    /// When the file has been downloaded before, the server is asked to only
+    /// send the file when it has changed since, based on the `ETag` and
+    /// `Last-Modified` headers of the previous download.
+    fn fetch(&self, url: &str, destination: &Path) -> Result<FetchResult, String> {
+        let partial_destination = destination.with_extension(partial_extension(&destination));
+        let filename = destination.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let partial_filename = partial_destination.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let cache = DownloadCache::new(&self.destination_folder);
+        let mut downloaded = fs::metadata(&partial_destination)
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        let resume_validator = match downloaded {
+            0 => None,
+            _ => cache.get(&partial_filename)
+                .filter(|entry| entry.url == url)
+                .and_then(|entry| get_range_validator(&entry))
+        };
+
+        if downloaded > 0 && resume_validator.is_none() {
+            _ = fs::remove_file(&partial_destination);
+            downloaded = 0;
+        }
+
+        let mut request = self.agent.get(url);
+
+        if let Some(validator) = &resume_validator {
+            request = request
+                .header("Range", &format!("bytes={}-", &downloaded))
+                .header("If-Range", validator);
+        } else if let Some(entry) = self.get_cache_entry(&url, &filename, &destination) {
+            if let Some(etag) = &entry.etag {
+                request = request.header("If-None-Match", etag);
+            }
+
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header("If-Modified-Since", last_modified);
+            }
+        }
+
+        let response = match request.call() {
+            Ok(response) if response.status() == NOT_MODIFIED => return Ok(FetchResult::NotModified),
+            Ok(response) => response,
+            Err(ureq::Error::StatusCode(RANGE_NOT_SATISFIABLE)) => {
+                _ = fs::remove_file(&partial_destination);
+                return Err(format!("Unable to resume the download of {}", &url));
+            },
+            Err(error) => return Err(error.to_string())
+        };
+
+        let cache_entry = CacheEntry {
+            url: url.to_string(),
+            etag: get_header(&response, "ETag"),
+            last_modified: get_header(&response, "Last-Modified")
+        };
+        let resumed = response.status() == PARTIAL_CONTENT;
+        let downloaded = if resumed { downloaded } else { 0 };
+        let content_length = response.body().content_length().map(|length| length + downloaded);
+        let mut reader = response.into_body().into_reader();
+
+        fs::create_dir_all(&self.destination_folder).map_err(|e| e.to_string())?;
+        let mut output = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resumed)
+            .truncate(!resumed)
+            .open(&partial_destination)
+            .map_err(|e| e.to_string())?;
+
+        // Failing to update the cache only means the `.part` file can't be resumed
+        _ = cache.set(&partial_filename, cache_entry.clone());
+
+        self.write_body(&url, &mut reader, &mut output, downloaded, content_length)?;
+        fs::rename(&partial_destination, &destination).map_err(|e| e.to_string())?;
+
+        // Failing to update the cache only means the file is downloaded again next time
+        _ = cache.set(&partial_filename, CacheEntry::default());
+        _ = cache.set(&filename, cache_entry);
+
+        return Ok(FetchResult::Downloaded);
+    }
+
+    /// Get the validators of the previous download, as long as that
+    /// file still exists and was downloaded from the same URL
+    fn get_cache_entry(&self, url: &str, filename: &str, destination: &Path) -> Option<CacheEntry> {
+        if !destination.is_file() {
+            return None;
+        }
+
+        return DownloadCache::new(&self.destination_folder)
+            .get(&filename)
+            .filter(|entry| entry.url == url);
+    }
+
+    fn write_body(
+        &self,
+        url: &str,
+        reader: &mut impl Read,
+        output: &mut impl Write,
+        mut downloaded: u64,
+        content_length: Option<u64>
+    ) -> Result<(), String> {
+        let mut buffer = vec![0u8; BUFFER_SIZE];
+        let mut reported_progress = String::default();
+
+        loop {
+            let read = reader.read(&mut buffer).map_err(|e| e.to_string())?;
+
+            if read == 0 {
+                break;
+            }
+
+            output.write_all(&buffer[..read]).map_err(|e| e.to_string())?;
+            downloaded += read as u64;
+
+            let progress = format_progress(downloaded, content_length);
+
+            if progress != reported_progress {
+                self.terminal_output.loading(&format!("Downloading {} ({})", &url, &progress));
+                reported_progress = progress;
+            }
+        }
+
+        return output.flush().map_err(|e| e.to_string());
+    }
+}
+
+fn get_header<T>(response: &Response<T>, name: &str) -> Option<String> {
+    return response.headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+}
+
+/// Get the validator which is sent as `If-Range`, weak `ETag` headers
+/// can't be used for ranges so the `Last-Modified` header is used instead
+fn get_range_validator(entry: &CacheEntry) -> Option<String> {
+    return entry.etag.clone()
+        .filter(|etag| !etag.starts_with("W/"))
+        .or(entry.last_modified.clone());
+}
+
+fn partial_extension(destination: &Path) -> String {
+    return match destination.extension() {
+        Some(extension) => format!("{}.part", extension.to_string_lossy()),
+        None => "part".to_string()
+    };
+}
+
+/// Show the percentage when the size is known, otherwise the amount of kilobytes
+fn format_progress(downloaded: u64, content_length: Option<u64>) -> String {
+    return match content_length {
+        Some(length) if length > 0 => format!("{}%", downloaded * 100 / length),
+        _ => format!("{} KB", downloaded / 1024)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use mockall::Sequence;
+    use crate::configuration::{DownloadFileConfiguration, GithubReleaseConfiguration};
+    use crate::file_download::download_cache::{CacheEntry, DownloadCache};
+    use crate::file_download::http_file_download::HttpFileDownload;
+    use crate::file_download::test_server::{request_path, response, serve};
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output_mock};
+
+    #[test]
+    fn download_saves_the_file_using_the_name_from_the_url() {
+        // Arrange
+        let (address, _) = serve(|_| response("200 OK", &[], b"upset"));
+        let files = vec!(DownloadFileConfiguration::from(format!("{}/release/upset.zip", &address)));
+        let destination_folder = create_test_folder("http", "url-name");
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+        assert!(!destination_folder.join("upset.zip.part").exists());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_saves_the_file_using_the_configured_filename() {
+        // Arrange
+        let (address, _) = serve(|_| response("200 OK", &[], b"upset"));
+        let files = vec!(DownloadFileConfiguration {
+            url: format!("{}/release/upset.zip", &address),
+            filename: Some("upset-v1.zip".to_string()),
+            ..Default::default()
+        });
+        let destination_folder = create_test_folder("http", "filename");
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset-v1.zip")).unwrap());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_follows_redirects() {
+        // Arrange
+        let (address, requests) = serve(|request| match request_path(&request).as_str() {
+            "/latest/upset.zip" => response("302 Found", &[("Location", "/v1.0.0/upset.zip")], b""),
+            _ => response("200 OK", &[], b"upset v1.0.0")
+        });
+        let files = vec!(DownloadFileConfiguration::from(format!("{}/latest/upset.zip", &address)));
+        let destination_folder = create_test_folder("http", "redirect");
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset v1.0.0", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+        assert_eq!("/v1.0.0/upset.zip", request_path(&requests.try_iter().last().unwrap()));
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_reports_the_progress_on_the_terminal_output() {
+        // Arrange
+        let (address, _) = serve(|_| response("200 OK", &[], b"upset"));
+        let files = vec!(DownloadFileConfiguration::from(format!("{}/upset.zip", &address)));
+        let destination_folder = create_test_folder("http", "progress");
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        let mut sequence = Sequence::new();
+
+        terminal_output_mock.expect_loading()
+            .withf(|message| !message.ends_with(')'))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+        terminal_output_mock.expect_loading()
+            .withf(|message| message.ends_with("(100%)"))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_calls_finish_with_warning_on_the_terminal_output_when_the_server_returns_an_error() {
+        // Arrange
+        let (address, _) = serve(|_| response("404 Not Found", &[], b""));
+        let files = vec!(DownloadFileConfiguration::from(format!("{}/upset.zip", &address)));
+        let destination_folder = create_test_folder("http", "not-found");
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert!(!destination_folder.join("upset.zip").exists());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_removes_the_file_and_calls_finish_with_warning_when_the_checksum_does_not_match() {
+        // Arrange
+        let (address, _) = serve(|_| response("200 OK", &[], b"upset"));
+        let files = vec!(DownloadFileConfiguration {
+            url: format!("{}/upset.zip", &address),
+            sha256: Some("0".repeat(64)),
+            ..Default::default()
+        });
+        let destination_folder = create_test_folder("http", "checksum-mismatch");
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert!(!destination_folder.join("upset.zip").exists());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_links_the_downloaded_file_when_link_to_is_set() {
+        // Arrange
+        let (address, _) = serve(|_| response("200 OK", &[], b"kubectl"));
+        let destination_folder = create_test_folder("http", "link-to");
+        let link_folder = destination_folder.join("bin");
+        let files = vec!(DownloadFileConfiguration {
+            url: format!("{}/kubectl", &address),
+            executable: true,
+            link_to: Some(format!("{}/", link_folder.to_string_lossy())),
+            ..Default::default()
+        });
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("kubectl", fs::read_to_string(link_folder.join("kubectl")).unwrap());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_downloads_the_asset_of_a_github_release() {
+        // Arrange
+        let (address, _) = serve(|request| match request_path(&request).as_str() {
+            "/repos/bartkessels/upset/releases/latest" => {
+                let address = request.lines()
+                    .find_map(|line| line.strip_prefix("host: ").or(line.strip_prefix("Host: ")))
+                    .unwrap()
+                    .to_string();
+                let release = format!(
+                    r#"{{ "assets": [{{ "name": "upset.zip", "browser_download_url": "http://{}/download/upset.zip" }}] }}"#,
+                    &address
+                );
+
+                response("200 OK", &[], release.as_bytes())
+            },
+            "/download/upset.zip" => response("200 OK", &[], b"upset"),
+            _ => response("404 Not Found", &[], b"")
+        });
+        let files = vec!(DownloadFileConfiguration {
+            github_release: Some(GithubReleaseConfiguration {
+                repository: "bartkessels/upset".to_string(),
+                asset: "upset.zip".to_string(),
+                api_url: address,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let destination_folder = create_test_folder("http", "github-release");
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_resumes_a_partially_downloaded_file() {
+        // Arrange
+        let (address, requests) = serve(|request| match request.to_lowercase().contains("range: bytes=3-") {
+            true => response("206 Partial Content", &[("Content-Range", "bytes 3-4/5")], b"et"),
+            false => response("200 OK", &[], b"upset")
+        });
+        let url = format!("{}/upset.zip", &address);
+        let files = vec!(DownloadFileConfiguration::from(url.to_string()));
+        let destination_folder = create_test_folder("http", "resume");
+        let terminal_output_mock = setup_terminal_output_mock();
+        fs::write(destination_folder.join("upset.zip.part"), "ups").unwrap();
+        DownloadCache::new(&destination_folder.to_string_lossy()).set("upset.zip.part", CacheEntry {
+            url,
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None
+        }).unwrap();
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+        let request = requests.recv().unwrap().to_lowercase();
+        assert!(request.contains("range: bytes=3-"));
+        assert!(request.contains("if-range: \"v1\""));
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_starts_over_when_the_server_does_not_support_resuming() {
+        // Arrange
+        let (address, _) = serve(|_| response("200 OK", &[], b"upset"));
+        let url = format!("{}/upset.zip", &address);
+        let files = vec!(DownloadFileConfiguration::from(url.to_string()));
+        let destination_folder = create_test_folder("http", "resume-unsupported");
+        let terminal_output_mock = setup_terminal_output_mock();
+        fs::write(destination_folder.join("upset.zip.part"), "ups").unwrap();
+        DownloadCache::new(&destination_folder.to_string_lossy()).set("upset.zip.part", CacheEntry {
+            url,
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None
+        }).unwrap();
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_removes_a_partially_downloaded_file_without_a_validator() {
+        // Arrange
+        let (address, requests) = serve(|_| response("200 OK", &[], b"upset"));
+        let files = vec!(DownloadFileConfiguration::from(format!("{}/upset.zip", &address)));
+        let destination_folder = create_test_folder("http", "resume-without-validator");
+        let terminal_output_mock = setup_terminal_output_mock();
+        fs::write(destination_folder.join("upset.zip.part"), "old").unwrap();
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+        assert!(!requests.recv().unwrap().to_lowercase().contains("range:"));
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_retries_a_failed_download() {
+        // Arrange
+        let attempts = AtomicUsize::new(0);
+        let (address, _) = serve(move |_| match attempts.fetch_add(1, Ordering::SeqCst) {
+            0 => response("503 Service Unavailable", &[], b""),
+            _ => response("200 OK", &[], b"upset")
+        });
+        let files = vec!(DownloadFileConfiguration {
+            url: format!("{}/upset.zip", &address),
+            retries: 1,
+            retry_delay: Some(0),
+            ..Default::default()
+        });
+        let destination_folder = create_test_folder("http", "retry");
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_uses_the_mirror_when_the_url_fails() {
+        // Arrange
+        let (address, _) = serve(|request| match request_path(&request).as_str() {
+            "/mirror/upset-latest.zip" => response("200 OK", &[], b"upset"),
+            _ => response("404 Not Found", &[], b"")
+        });
+        let files = vec!(DownloadFileConfiguration {
+            url: format!("{}/upset.zip", &address),
+            mirrors: vec!(format!("{}/mirror/upset-latest.zip", &address)),
+            ..Default::default()
+        });
+        let destination_folder = create_test_folder("http", "mirror");
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_does_not_download_the_file_again_when_it_matches_the_checksum() {
+        // Arrange
+        let (address, requests) = serve(|_| response("200 OK", &[], b"upset v2"));
+        let files = vec!(DownloadFileConfiguration {
+            url: format!("{}/upset.zip", &address),
+            sha256: Some("8b2f406339328a5dd4c6586a2da0d35978b7a9a13bf1bb0c38274e6ba245964a".to_string()),
+            ..Default::default()
+        });
+        let destination_folder = create_test_folder("http", "checksum-up-to-date");
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(destination_folder.join("upset.zip"), "upset").unwrap();
+
+        // Setup the expectations
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+        assert!(requests.try_recv().is_err());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_asks_the_server_whether_the_file_has_changed_since_the_previous_download() {
+        // Arrange
+        let (address, requests) = serve(|request| match request.to_lowercase().contains("if-none-match: \"v1\"") {
+            true => response("304 Not Modified", &[], b""),
+            false => response("200 OK", &[("ETag", "\"v1\""), ("Last-Modified", "Mon, 01 Jan 2024 00:00:00 GMT")], b"upset")
+        });
+        let files = vec!(DownloadFileConfiguration::from(format!("{}/upset.zip", &address)));
+        let destination_folder = create_test_folder("http", "not-modified");
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        let mut sequence = Sequence::new();
+
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.starts_with("Successfully downloaded"))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+        _ = sut.download(&files);
+
+        // Assert
+        let second_request = requests.iter().nth(1).unwrap().to_lowercase();
+        assert!(second_request.contains("if-modified-since: mon, 01 jan 2024 00:00:00 gmt"));
+        assert_eq!("upset", fs::read_to_string(destination_folder.join("upset.zip")).unwrap());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_does_not_link_the_file_again_when_it_matches_the_checksum() {
+        // Arrange
+        let (address, requests) = serve(|_| response("200 OK", &[], b"upset v2"));
+        let destination_folder = create_test_folder("http", "checksum-link-to");
+        let link_folder = destination_folder.join("bin");
+        let files = vec!(DownloadFileConfiguration {
+            url: format!("{}/upset.zip", &address),
+            sha256: Some("8b2f406339328a5dd4c6586a2da0d35978b7a9a13bf1bb0c38274e6ba245964a".to_string()),
+            link_to: Some(format!("{}/", link_folder.to_string_lossy())),
+            ..Default::default()
+        });
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(destination_folder.join("upset.zip"), "upset").unwrap();
+
+        // Setup the expectations
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+
+        // Assert
+        assert!(!link_folder.join("upset.zip").exists());
+        assert!(requests.try_recv().is_err());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_verifies_the_checksum_when_the_file_has_not_changed() {
+        // Arrange
+        let (address, _) = serve(|request| match request.to_lowercase().contains("if-none-match: \"v1\"") {
+            true => response("304 Not Modified", &[], b""),
+            false => response("200 OK", &[("ETag", "\"v1\"")], b"upset")
+        });
+        let url = format!("{}/upset.zip", &address);
+        let verified_file = DownloadFileConfiguration {
+            url: url.to_string(),
+            sha256: Some("0000000000000000000000000000000000000000000000000000000000000000".to_string()),
+            ..Default::default()
+        };
+        let destination_folder = create_test_folder("http", "not-modified-checksum");
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .once()
+            .returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&vec!(DownloadFileConfiguration::from(url)));
+        _ = sut.download(&vec!(verified_file));
+
+        // Assert
+        assert!(!destination_folder.join("upset.zip").exists());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+
+    #[test]
+    fn download_downloads_the_file_again_when_it_has_been_removed() {
+        // Arrange
+        let (address, requests) = serve(|_| response("200 OK", &[("ETag", "\"v1\"")], b"upset"));
+        let files = vec!(DownloadFileConfiguration::from(format!("{}/upset.zip", &address)));
+        let destination_folder = create_test_folder("http", "removed");
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Act
+        let sut = HttpFileDownload::new(
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&files);
+        fs::remove_file(destination_folder.join("upset.zip")).unwrap();
+        _ = sut.download(&files);
+
+        // Assert
+        let second_request = requests.iter().nth(1).unwrap().to_lowercase();
+        assert!(!second_request.contains("if-none-match"));
+        assert!(destination_folder.join("upset.zip").exists());
+
+        // Teardown
+        delete_test_folder(&destination_folder);
+    }
+}
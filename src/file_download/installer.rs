@@ -0,0 +1,272 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::command::Command;
+use crate::commands::{AptCommand, InstallerCommand, MsiexecCommand, RpmCommand};
+use crate::file_download::executable::make_executable;
+
+#[derive(Debug, PartialEq)]
+enum PackageType {
+    Deb,
+    Rpm,
+    Msi,
+    Pkg,
+    AppImage
+}
+
+/// The commands which install each type of package
+pub struct InstallerCommands {
+    /// The apt command, used for `.deb` packages
+    pub apt_command: Arc<dyn Command>,
+
+    /// The rpm command, used for `.rpm` packages
+    pub rpm_command: Arc<dyn Command>,
+
+    /// The msiexec command, used for `.msi` packages
+    pub msiexec_command: Arc<dyn Command>,
+
+    /// The macOS installer command, used for `.pkg` packages
+    pub installer_command: Arc<dyn Command>
+}
+
+impl InstallerCommands {
+    pub fn new() -> Self {
+        return Self {
+            apt_command: AptCommand::new(),
+            rpm_command: RpmCommand::new(),
+            msiexec_command: MsiexecCommand::new(),
+            installer_command: InstallerCommand::new()
+        };
+    }
+}
+
+/// Install the downloaded package using the installer of the operating system
+///
+/// The type of the package is determined by its file name, supported packages
+/// are `.deb`, `.rpm`, `.msi`, `.pkg` and `.AppImage`. AppImages don't need to
+/// be installed and are only made executable
+pub fn install_package(package: &Path, commands: &InstallerCommands) -> Result<(), String> {
+    let package_type = get_package_type(&package)
+        .ok_or(format!("Unsupported package {}", &package.to_string_lossy()))?;
+
+    let installer = match package_type {
+        PackageType::Deb => &commands.apt_command,
+        PackageType::Rpm => &commands.rpm_command,
+        PackageType::Msi => &commands.msiexec_command,
+        PackageType::Pkg => &commands.installer_command,
+        PackageType::AppImage => {
+            return make_executable(&package)
+                .map_err(|_| format!("Unable to make {} executable", &package.to_string_lossy()));
+        }
+    };
+
+    return run_installer(installer, &package_type, &package);
+}
+
+fn get_package_type(package: &Path) -> Option<PackageType> {
+    let name = package.file_name()?.to_string_lossy().to_lowercase();
+
+    return match Path::new(&name).extension()?.to_str()? {
+        "deb" => Some(PackageType::Deb),
+        "rpm" => Some(PackageType::Rpm),
+        "msi" => Some(PackageType::Msi),
+        "pkg" => Some(PackageType::Pkg),
+        "appimage" => Some(PackageType::AppImage),
+        _ => None
+    };
+}
+
+fn run_installer(installer: &Arc<dyn Command>, package_type: &PackageType, package: &Path) -> Result<(), String> {
+    return match installer.execute(&get_arguments(&package_type, &package)) {
+        Ok(true) => Ok(()),
+        _ => Err(format!("Unable to install {}", &package.to_string_lossy()))
+    };
+}
+
+/// Rpm upgrades the package, so a newer release replaces the installed
+/// one instead of conflicting with it like `rpm -i` would
+fn get_arguments(package_type: &PackageType, package: &Path) -> Vec<String> {
+    let package = get_package_path(&package);
+
+    return match package_type {
+        PackageType::Deb => vec!("install".to_string(), "-y".to_string(), package),
+        PackageType::Rpm => vec!("-U".to_string(), package),
+        PackageType::Msi => vec!("/i".to_string(), package, "/qn".to_string()),
+        PackageType::Pkg => vec!(
+            "-pkg".to_string(),
+            package,
+            "-target".to_string(),
+            "/".to_string()
+        ),
+        PackageType::AppImage => Vec::new()
+    };
+}
+
+/// Apt only treats the argument as a file when it looks like a path,
+/// so relative paths are prefixed with the current folder
+fn get_package_path(package: &Path) -> String {
+    if package.is_absolute() {
+        return package.to_string_lossy().to_string();
+    }
+
+    return PathBuf::from(".").join(&package).to_string_lossy().to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::sync::Arc;
+    use crate::command::{Command, MockCommand};
+    use crate::file_download::installer::{get_arguments, get_package_type, install_package, run_installer, InstallerCommands, PackageType};
+
+    #[test]
+    fn get_package_type_is_determined_by_the_file_name() {
+        assert_eq!(Some(PackageType::Deb), get_package_type(Path::new("/tmp/upset_1.0.0_amd64.deb")));
+        assert_eq!(Some(PackageType::Rpm), get_package_type(Path::new("/tmp/upset-1.0.0.x86_64.rpm")));
+        assert_eq!(Some(PackageType::Msi), get_package_type(Path::new("C:/Temp/Upset.MSI")));
+        assert_eq!(Some(PackageType::Pkg), get_package_type(Path::new("/tmp/upset.pkg")));
+        assert_eq!(Some(PackageType::AppImage), get_package_type(Path::new("/tmp/upset-x86_64.AppImage")));
+        assert_eq!(None, get_package_type(Path::new("/tmp/upset.tar.gz")));
+    }
+
+    #[test]
+    fn get_arguments_returns_the_arguments_for_each_installer() {
+        assert_eq!(
+            vec!("install", "-y", "/tmp/upset.deb"),
+            get_arguments(&PackageType::Deb, Path::new("/tmp/upset.deb"))
+        );
+        assert_eq!(
+            vec!("-U", "/tmp/upset.rpm"),
+            get_arguments(&PackageType::Rpm, Path::new("/tmp/upset.rpm"))
+        );
+        assert_eq!(
+            vec!("/i", "/tmp/upset.msi", "/qn"),
+            get_arguments(&PackageType::Msi, Path::new("/tmp/upset.msi"))
+        );
+        assert_eq!(
+            vec!("-pkg", "/tmp/upset.pkg", "-target", "/"),
+            get_arguments(&PackageType::Pkg, Path::new("/tmp/upset.pkg"))
+        );
+    }
+
+    #[test]
+    fn get_arguments_prefixes_relative_packages_with_the_current_folder() {
+        // Act
+        let result = get_arguments(&PackageType::Deb, Path::new("Downloads/upset.deb"));
+
+        // Assert
+        assert_eq!("./Downloads/upset.deb", result[2]);
+    }
+
+    #[test]
+    fn run_installer_returns_ok_when_the_installer_succeeded() {
+        // Arrange
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &["-U".to_string(), "/tmp/upset.rpm".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let result = run_installer(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &PackageType::Rpm,
+            Path::new("/tmp/upset.rpm")
+        );
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn run_installer_returns_error_when_the_installer_fails() {
+        // Arrange
+        let mut command_mock = MockCommand::new();
+
+        // Setup the mocks
+        command_mock.expect_execute().returning(|_| Ok(false));
+
+        // Act
+        let result = run_installer(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &PackageType::Deb,
+            Path::new("/tmp/upset.deb")
+        );
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn run_installer_returns_error_when_the_installer_can_not_be_found() {
+        // Arrange
+        let mut command_mock = MockCommand::new();
+
+        // Setup the mocks
+        command_mock.expect_execute().returning(|_| Err(String::default()));
+
+        // Act
+        let result = run_installer(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &PackageType::Msi,
+            Path::new("/tmp/upset.msi")
+        );
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn install_package_uses_the_installer_of_the_package_type() {
+        // Arrange
+        let mut apt_mock = MockCommand::new();
+        let mut rpm_mock = MockCommand::new();
+        let mut msiexec_mock = MockCommand::new();
+        let mut installer_mock = MockCommand::new();
+
+        // Setup the expectations
+        apt_mock.expect_execute().never();
+        rpm_mock.expect_execute()
+            .withf(|args| args.last().unwrap() == "/tmp/upset.rpm")
+            .once()
+            .returning(|_| Ok(true));
+        msiexec_mock.expect_execute().never();
+        installer_mock.expect_execute().never();
+
+        // Act
+        let result = install_package(
+            Path::new("/tmp/upset.rpm"),
+            &create_installer_commands(apt_mock, rpm_mock, msiexec_mock, installer_mock)
+        );
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn install_package_returns_error_for_unsupported_packages() {
+        // Act
+        let result = install_package(
+            Path::new("/tmp/upset.tar.gz"),
+            &create_installer_commands(MockCommand::new(), MockCommand::new(), MockCommand::new(), MockCommand::new())
+        );
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    fn create_installer_commands(
+        apt_mock: MockCommand,
+        rpm_mock: MockCommand,
+        msiexec_mock: MockCommand,
+        installer_mock: MockCommand
+    ) -> InstallerCommands {
+        return InstallerCommands {
+            apt_command: Arc::new(apt_mock),
+            rpm_command: Arc::new(rpm_mock),
+            msiexec_command: Arc::new(msiexec_mock),
+            installer_command: Arc::new(installer_mock)
+        };
+    }
+}
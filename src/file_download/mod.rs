@@ -1,6 +1,20 @@
 mod file_download;
+mod archive;
+mod checksum;
+mod destination;
+mod download_cache;
+mod download_pipeline;
+mod executable;
+mod github_release;
+mod installer;
+mod post_download;
+mod retry;
 mod wget_file_download;
+mod curl_file_download;
+mod http_file_download;
 mod file_download_factory;
+#[cfg(test)]
+mod test_server;
 
 pub use file_download::MockFileDownload;
 pub use file_download::FileDownload;
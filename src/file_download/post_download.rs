@@ -0,0 +1,50 @@
+use std::fs;
+use std::path::Path;
+use crate::configuration::DownloadFileConfiguration;
+use crate::file_download::archive::extract_archive;
+use crate::file_download::checksum::verify_checksum;
+use crate::file_download::executable::{create_link, make_executable};
+use crate::file_download::installer::{install_package, InstallerCommands};
+
+/// Verify the checksum of the file, removing it when it doesn't match
+pub fn verify_downloaded_file(file: &DownloadFileConfiguration, destination: &Path) -> Result<(), String> {
+    if let Err(error) = verify_checksum(&file, &destination) {
+        _ = fs::remove_file(&destination);
+        return Err(error);
+    }
+
+    return Ok(());
+}
+
+/// Process a file after it has been downloaded successfully
+///
+/// Verifies the checksum of the file, removing it when it doesn't match,
+/// and extracts it, makes it executable, links it and installs it when this has been configured.
+/// Only files which have actually been downloaded are processed, so these steps aren't repeated
+/// for files which are up to date
+pub fn process_downloaded_file(file: &DownloadFileConfiguration, destination: &Path) -> Result<(), String> {
+    verify_downloaded_file(&file, &destination)?;
+
+    if let Some(extract) = &file.extract {
+        let destination_folder = destination.parent().unwrap_or(Path::new("."));
+
+        extract_archive(&destination, &destination_folder, &extract)
+            .map_err(|_| format!("Unable to extract {}", &file.url))?;
+    }
+
+    if file.executable {
+        make_executable(&destination)
+            .map_err(|_| format!("Unable to make {} executable", &destination.to_string_lossy()))?;
+    }
+
+    if let Some(link_to) = &file.link_to {
+        create_link(&destination, &link_to)
+            .map_err(|_| format!("Unable to link {} to {}", &destination.to_string_lossy(), &link_to))?;
+    }
+
+    if file.install {
+        install_package(&destination, &InstallerCommands::new())?;
+    }
+
+    return Ok(());
+}
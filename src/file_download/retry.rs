@@ -0,0 +1,138 @@
+use std::iter;
+use std::thread;
+use std::time::Duration;
+use crate::configuration::DownloadFileConfiguration;
+
+/// Seconds to wait before the first retry when no delay has been configured
+const DEFAULT_RETRY_DELAY: u64 = 1;
+
+/// Download the file from its URL, falling back to the mirrors in order
+///
+/// Every location is attempted `retries + 1` times before moving on to the next,
+/// waiting `retry_delay` seconds, or one second by default, before the first retry and doubling the delay
+/// for every retry after that. Returns the error of the last attempt when the
+/// file couldn't be downloaded from any of the locations.
+pub fn download_with_retries<T, F>(file: &DownloadFileConfiguration, mut download: F) -> Result<T, String>
+where
+    F: FnMut(&str) -> Result<T, String>
+{
+    let mut last_error = format!("Unable to download {}", &file.url);
+
+    for url in iter::once(&file.url).chain(file.mirrors.iter()) {
+        let mut delay = Duration::from_secs(file.retry_delay.unwrap_or(DEFAULT_RETRY_DELAY));
+
+        for attempt in 0..=file.retries {
+            if attempt > 0 {
+                thread::sleep(delay);
+                delay *= 2;
+            }
+
+            match download(&url) {
+                Ok(result) => return Ok(result),
+                Err(error) => last_error = error
+            }
+        }
+    }
+
+    return Err(last_error);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configuration::DownloadFileConfiguration;
+    use crate::file_download::retry::download_with_retries;
+
+    #[test]
+    fn download_with_retries_only_attempts_the_url_once_by_default() {
+        // Arrange
+        let file = DownloadFileConfiguration::from("https://bartkessels.net/upset.zip".to_string());
+        let mut attempts = Vec::new();
+
+        // Act
+        let result: Result<(), String> = download_with_retries(&file, |url| {
+            attempts.push(url.to_string());
+            Err("Unable to download".to_string())
+        });
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(vec!("https://bartkessels.net/upset.zip".to_string()), attempts);
+    }
+
+    #[test]
+    fn download_with_retries_retries_the_url_until_it_succeeds() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            retries: 3,
+            retry_delay: Some(0),
+            ..Default::default()
+        };
+        let mut attempts = 0;
+
+        // Act
+        let result = download_with_retries(&file, |_| {
+            attempts += 1;
+
+            match attempts {
+                3 => Ok(attempts),
+                _ => Err("Unable to download".to_string())
+            }
+        });
+
+        // Assert
+        assert_eq!(Ok(3), result);
+    }
+
+    #[test]
+    fn download_with_retries_tries_the_mirrors_in_order_after_the_url_fails() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            mirrors: vec!(
+                "https://mirror1.bartkessels.net/upset.zip".to_string(),
+                "https://mirror2.bartkessels.net/upset.zip".to_string()
+            ),
+            retries: 1,
+            retry_delay: Some(0),
+            ..Default::default()
+        };
+        let mut attempts = Vec::new();
+
+        // Act
+        let result = download_with_retries(&file, |url| {
+            attempts.push(url.to_string());
+
+            match url.contains("mirror2") {
+                true => Ok(()),
+                false => Err("Unable to download".to_string())
+            }
+        });
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(vec!(
+            "https://bartkessels.net/upset.zip".to_string(),
+            "https://bartkessels.net/upset.zip".to_string(),
+            "https://mirror1.bartkessels.net/upset.zip".to_string(),
+            "https://mirror1.bartkessels.net/upset.zip".to_string(),
+            "https://mirror2.bartkessels.net/upset.zip".to_string()
+        ), attempts);
+    }
+
+    #[test]
+    fn download_with_retries_returns_the_last_error_when_all_locations_fail() {
+        // Arrange
+        let file = DownloadFileConfiguration {
+            url: "https://bartkessels.net/upset.zip".to_string(),
+            mirrors: vec!("https://mirror.bartkessels.net/upset.zip".to_string()),
+            ..Default::default()
+        };
+
+        // Act
+        let result: Result<(), String> = download_with_retries(&file, |url| Err(url.to_string()));
+
+        // Assert
+        assert_eq!(Err("https://mirror.bartkessels.net/upset.zip".to_string()), result);
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::thread;
+
+/// Minimal HTTP server used as a stand-in for remote hosts in tests
+///
+/// Every request is answered by the handler, which receives the raw request
+/// head and returns the raw response. The request heads are sent back through
+/// the returned receiver so tests can assert on them.
+pub fn serve<F>(handler: F) -> (String, Receiver<String>)
+where
+    F: Fn(&str) -> Vec<u8> + Send + 'static
+{
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Unable to start the test server");
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let request = read_request_head(&mut stream);
+            let response = handler(&request);
+
+            _ = sender.send(request);
+            _ = stream.write_all(&response);
+            _ = stream.flush();
+        }
+    });
+
+    return (address, receiver);
+}
+
+/// Build a raw HTTP response with the given status line, headers and body
+pub fn response(status: &str, headers: &[(&str, &str)], body: &[u8]) -> Vec<u8> {
+    let mut response = format!(
+        "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        &status,
+        body.len()
+    );
+
+    for (name, value) in headers {
+        response.push_str(&format!("{}: {}\r\n", &name, &value));
+    }
+
+    response.push_str("\r\n");
+
+    let mut response = response.into_bytes();
+    response.extend_from_slice(&body);
+
+    return response;
+}
+
+/// Get the path of the request line, e.g. `/file.zip` for `GET /file.zip HTTP/1.1`
+pub fn request_path(request: &str) -> String {
+    return request.split_whitespace().nth(1).unwrap_or_default().to_string();
+}
+
+fn read_request_head(stream: &mut impl Read) -> String {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1];
+
+    while !head.ends_with(b"\r\n\r\n") {
+        match stream.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => head.push(buffer[0])
+        }
+    }
+
+    return String::from_utf8_lossy(&head).to_string();
+}
@@ -1,7 +1,11 @@
 use std::any::Any;
+use std::path::Path;
 use std::sync::Arc;
 use crate::command::Command;
+use crate::configuration::DownloadFileConfiguration;
+use crate::file_download::download_pipeline::{download_file, FetchResult};
 use crate::file_download::file_download::FileDownload;
+use crate::path::expand_home_directory;
 use crate::terminal::TerminalOutput;
 
 pub struct WgetFileDownload {
@@ -15,9 +19,9 @@ pub struct WgetFileDownload {
 }
 
 impl FileDownload for WgetFileDownload {
-    fn download(&self, remote_sources: &Vec<String>) {
-        for remote_source in remote_sources {
-            self.download_file(&remote_source);
+    fn download(&self, files: &Vec<DownloadFileConfiguration>) {
+        for file in files {
+            self.download_file(&file);
         }
     }
 
@@ -34,45 +38,64 @@ impl WgetFileDownload {
     ) -> Arc<dyn FileDownload> {
         return Arc::new(Self {
             wget_command: wget_command.clone(),
-            destination_folder: destination_folder.to_string(),
+            destination_folder: expand_home_directory(&destination_folder),
             terminal_output: terminal_output.clone()
         });
     }
 
-    pub fn download_file(&self, remote_source: &String) {
-        self.terminal_output.loading(&format!("Downloading {}", &remote_source));
+    pub fn download_file(&self, file: &DownloadFileConfiguration) {
+        download_file(&file, &self.destination_folder, &self.terminal_output, |url, destination, resume| {
+            let arguments = get_arguments(&url, &destination, resume);
 
-        let result = self.wget_command
-            .execute(&[
-                remote_source.to_string(),
-                "-O".to_string(),
-                self.destination_folder.to_string()
-            ]);
-
-        if result.is_ok_and(|output| output) {
-            self.terminal_output.finish_with_success(
-                &format!("Successfully downloaded {}", &remote_source)
-            );
-        } else {
-            self.terminal_output.finish_with_warning(
-                &format!("Unable to download {}", &remote_source)
-            );
-        }
+            return match self.wget_command.execute(&arguments) {
+                Ok(true) => Ok(FetchResult::Downloaded),
+                _ => Err(format!("Unable to download {}", &url))
+            };
+        });
+    }
+}
+
+/// Write the download to its destination, so a file from an earlier
+/// download is replaced instead of being saved next to it
+///
+/// Only retries continue the partially downloaded file, because that file
+/// was left by the failed attempt before it. The first attempt starts over,
+/// so a file which has grown since the previous download isn't appended to.
+fn get_arguments(url: &str, destination: &Path, resume: bool) -> Vec<String> {
+    let mut arguments = vec!(url.to_string());
+
+    if resume {
+        arguments.push("-c".to_string());
     }
+
+    arguments.extend([
+        "-O".to_string(),
+        destination.to_string_lossy().to_string()
+    ]);
+
+    return arguments;
 }
 
 #[cfg(test)]
 mod tests {
+    use std::env;
+    use std::fs;
     use std::sync::Arc;
     use mockall::Sequence;
     use crate::command::{Command, MockCommand};
+    use crate::configuration::DownloadFileConfiguration;
     use crate::file_download::wget_file_download::WgetFileDownload;
+    use crate::path::expand_home_directory;
     use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output_mock;
 
     #[test]
     fn download_calls_the_wget_command_for_each_file() {
         // Arrange
-        let remote_sources = vec!("remote1".to_string(), "remote2".to_string());
+        let remote_sources = vec!(
+            DownloadFileConfiguration::from("https://bartkessels.net/remote1".to_string()),
+            DownloadFileConfiguration::from("https://bartkessels.net/remote2".to_string())
+        );
         let destination_folder = "~/Downloads";
         let mut command_mock = MockCommand::new();
         let terminal_output_mock = setup_terminal_output_mock();
@@ -92,11 +115,11 @@ mod tests {
     }
 
     #[test]
-    fn install_calls_the_wget_command_with_the_expected_arguments() {
+    fn download_calls_the_wget_command_with_the_expected_arguments() {
         // Arrange
         let remote_source = "https://github.com/bartkessels/upset/release.zip";
-        let remote_sources = vec!(remote_source.to_string());
-        let destination_folder = "~/Downloads";
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
+        let destination_folder = "/tmp/Downloads";
         let mut command_mock = MockCommand::new();
         let terminal_output_mock = setup_terminal_output_mock();
 
@@ -105,7 +128,66 @@ mod tests {
             .withf(|args| args == &[
                 remote_source.to_string(),
                 "-O".to_string(),
-                destination_folder.to_string()
+                "/tmp/Downloads/release.zip".to_string()
+            ])
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = WgetFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_calls_the_wget_command_with_the_output_file_when_a_filename_is_set() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: remote_source.to_string(),
+            filename: Some("upset.zip".to_string()),
+            ..Default::default()
+        });
+        let destination_folder = "/tmp/Downloads";
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &[
+                remote_source.to_string(),
+                "-O".to_string(),
+                "/tmp/Downloads/upset.zip".to_string()
+            ])
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = WgetFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_calls_the_wget_command_with_the_home_directory_expanded() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
+        let destination_folder = "~/Downloads";
+        let expected_destination_folder = expand_home_directory(&destination_folder);
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(move |args| args == &[
+                remote_source.to_string(),
+                "-O".to_string(),
+                format!("{}/release.zip", &expected_destination_folder)
             ])
             .returning(|_| Ok(true));
 
@@ -122,7 +204,7 @@ mod tests {
     fn download_calls_loading_on_the_the_terminal_output_before_the_command_is_being_executed() {
         // Arrange
         let remote_source = "https://github.com/bartkessels/upset/release.zip";
-        let remote_sources = vec!(remote_source.to_string());
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
         let destination_folder = "~/Downloads";
         let mut command_mock = MockCommand::new();
         let mut terminal_output_mock = MockTerminalOutput::new();
@@ -155,7 +237,7 @@ mod tests {
     fn download_calls_the_finish_with_success_on_the_the_terminal_output_when_the_command_succeeded() {
         // Arrange
         let remote_source = "https://github.com/bartkessels/upset/release.zip";
-        let remote_sources = vec!(remote_source.to_string());
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
         let destination_folder = "~/Downloads";
         let mut command_mock = MockCommand::new();
         let mut terminal_output_mock = MockTerminalOutput::new();
@@ -182,7 +264,7 @@ mod tests {
     fn download_calls_finish_with_warning_on_the_the_terminal_output_when_the_command_fails() {
         // Arrange
         let remote_source = "https://github.com/bartkessels/upset/release.zip";
-        let remote_sources = vec!(remote_source.to_string());
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
         let destination_folder = "~/Downloads";
         let mut command_mock = MockCommand::new();
         let mut terminal_output_mock = MockTerminalOutput::new();
@@ -209,7 +291,7 @@ mod tests {
     fn download_calls_finish_with_warning_on_the_the_terminal_output_when_the_command_returns_an_error() {
         // Arrange
         let remote_source = "https://github.com/bartkessels/upset/release.zip";
-        let remote_sources = vec!(remote_source.to_string());
+        let remote_sources = vec!(DownloadFileConfiguration::from(remote_source.to_string()));
         let destination_folder = "~/Downloads";
         let mut command_mock = MockCommand::new();
         let mut terminal_output_mock = MockTerminalOutput::new();
@@ -232,14 +314,200 @@ mod tests {
         _ = sut.download(&remote_sources);
     }
 
-    fn setup_terminal_output_mock() -> MockTerminalOutput {
+    #[test]
+    fn download_removes_the_file_and_calls_finish_with_warning_when_the_checksum_does_not_match() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: remote_source.to_string(),
+            sha256: Some("0".repeat(64)),
+            ..Default::default()
+        });
+        let destination_folder = env::temp_dir().join("upset-wget-checksum-mismatch");
+        let destination = destination_folder.join("release.zip");
+        let downloaded_file = destination.clone();
+        let mut command_mock = MockCommand::new();
         let mut terminal_output_mock = MockTerminalOutput::new();
 
+        fs::create_dir_all(&destination_folder).expect("Unable to create the destination folder");
+
+        // Setup the mocks
+        command_mock.expect_execute().returning(move |_| {
+            fs::write(&downloaded_file, "upset").expect("Unable to write the downloaded file");
+            Ok(true)
+        });
+
         // Setup the expectation
         terminal_output_mock.expect_loading().returning(|_| {});
-        terminal_output_mock.expect_finish_with_success().returning(|_| {});
-        terminal_output_mock.expect_finish_with_warning().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = WgetFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+
+        // Assert
+        assert!(!destination.exists());
+
+        // Teardown
+        fs::remove_dir_all(&destination_folder).expect("Unable to delete the destination folder");
+    }
+
+    #[test]
+    fn download_calls_the_wget_command_for_the_mirror_with_the_output_file_of_the_url() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let mirror = "https://mirror.bartkessels.net/upset/latest";
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: remote_source.to_string(),
+            mirrors: vec!(mirror.to_string()),
+            ..Default::default()
+        });
+        let destination_folder = "/tmp/Downloads";
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectations
+        let mut sequence = Sequence::new();
+
+        command_mock.expect_execute()
+            .withf(move |args| args[0] == remote_source)
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(false));
+        command_mock.expect_execute()
+            .withf(|args| args == &[
+                mirror.to_string(),
+                "-c".to_string(),
+                "-O".to_string(),
+                "/tmp/Downloads/release.zip".to_string()
+            ])
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = WgetFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_retries_the_wget_command_when_it_fails() {
+        // Arrange
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: "https://github.com/bartkessels/upset/release.zip".to_string(),
+            retries: 2,
+            retry_delay: Some(0),
+            ..Default::default()
+        });
+        let destination_folder = "/tmp/Downloads";
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the mocks
+        command_mock.expect_execute()
+            .times(3)
+            .returning(|_| Ok(false));
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = WgetFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_only_continues_the_partially_downloaded_file_when_retrying() {
+        // Arrange
+        let remote_source = "https://github.com/bartkessels/upset/release.zip";
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: remote_source.to_string(),
+            retries: 1,
+            retry_delay: Some(0),
+            ..Default::default()
+        });
+        let destination_folder = "/tmp/Downloads";
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectations
+        let mut sequence = Sequence::new();
+
+        command_mock.expect_execute()
+            .withf(|args| !args.contains(&"-c".to_string()))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(false));
+        command_mock.expect_execute()
+            .withf(|args| args == &[
+                remote_source.to_string(),
+                "-c".to_string(),
+                "-O".to_string(),
+                "/tmp/Downloads/release.zip".to_string()
+            ])
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = WgetFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder,
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
+    }
+
+    #[test]
+    fn download_does_not_call_the_wget_command_when_the_file_matches_the_checksum() {
+        // Arrange
+        let destination_folder = env::temp_dir().join("upset-wget-up-to-date");
+        let remote_sources = vec!(DownloadFileConfiguration {
+            url: "https://github.com/bartkessels/upset/release.zip".to_string(),
+            sha256: Some("8b2f406339328a5dd4c6586a2da0d35978b7a9a13bf1bb0c38274e6ba245964a".to_string()),
+            ..Default::default()
+        });
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        fs::create_dir_all(&destination_folder).expect("Unable to create the destination folder");
+        fs::write(destination_folder.join("release.zip"), "upset").expect("Unable to write the downloaded file");
+
+        // Setup the expectations
+        command_mock.expect_execute()
+            .never()
+            .returning(|_| Ok(true));
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = WgetFileDownload::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &destination_folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.download(&remote_sources);
 
-        return terminal_output_mock;
+        // Teardown
+        fs::remove_dir_all(&destination_folder).expect("Unable to delete the destination folder");
     }
 }
\ No newline at end of file
@@ -29,6 +29,8 @@ mod tests {
     use std::fs;
     use std::fs::File;
     use std::io::Write;
+    use std::collections::BTreeMap;
+    use crate::configuration::{ContainerImageConfiguration, ContainerImagesConfiguration, DirectoryConfiguration, DotfileLinkConfiguration, DotfilesConfiguration, DownloadFileConfiguration, EnvironmentConfiguration, ExtractConfiguration, FileCopyConfiguration, GroupConfiguration, KnownHostConfiguration, LineInFileConfiguration, LineState, RuntimeConfiguration, ScheduleConfiguration, ScriptConfiguration, ServiceConfiguration, ServiceScope, ServiceState, SshConfiguration, SshHostConfiguration, SshKeyConfiguration, TemplateConfiguration};
     use crate::file_reader::YamlFileReader;
 
     #[test]
@@ -111,6 +113,20 @@ mod tests {
             assert!(result.configuration.packages.is_none());
             assert!(result.configuration.version_control.is_none());
             assert!(result.configuration.downloads.is_none());
+            assert!(result.configuration.files.is_none());
+            assert!(result.configuration.scripts.is_none());
+            assert!(result.configuration.dotfiles.is_none());
+            assert!(result.configuration.templates.is_none());
+            assert!(result.configuration.directories.is_none());
+            assert!(result.configuration.environment.is_none());
+            assert!(result.configuration.line_in_file.is_none());
+            assert!(result.configuration.ssh.is_none());
+            assert!(result.configuration.services.is_none());
+            assert!(result.configuration.schedules.is_none());
+            assert!(result.configuration.fonts.is_none());
+            assert!(result.configuration.runtimes.is_none());
+            assert!(result.configuration.container_images.is_none());
+            assert!(result.configuration.groups.is_none());
         }
 
         // Teardown
@@ -221,8 +237,8 @@ configuration:
                 assert_eq!("wget".to_string(), downloads.first().unwrap().download_manager);
                 assert_eq!("~/Downloads".to_string(), downloads.first().unwrap().destination_folder);
                 assert_eq!(vec!(
-                    "https://bartkessels.net/download/upset".to_string(),
-                    "https://bartkessels.net/download/it-depends".to_string()
+                    DownloadFileConfiguration::from("https://bartkessels.net/download/upset".to_string()),
+                    DownloadFileConfiguration::from("https://bartkessels.net/download/it-depends".to_string())
                 ), downloads.first().unwrap().files);
             }
         }
@@ -231,6 +247,760 @@ configuration:
         delete_test_file(&file_path);
     }
 
+    #[test]
+    fn read_configuration_should_parse_the_file_downloads_with_a_filename() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  downloads:
+    - download_manager: wget
+      destination_folder: ~/Downloads
+      files:
+        - https://bartkessels.net/download/upset
+        - url: https://bartkessels.net/download/it-depends
+          filename: it-depends.zip
+";
+        let file_path = write_test_file(&configuration, "downloads-filename");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert!(result.configuration.downloads.is_some());
+
+            if let Some(downloads) = result.configuration.downloads {
+                assert_eq!(vec!(
+                    DownloadFileConfiguration::from("https://bartkessels.net/download/upset".to_string()),
+                    DownloadFileConfiguration {
+                        url: "https://bartkessels.net/download/it-depends".to_string(),
+                        filename: Some("it-depends.zip".to_string()),
+                        ..Default::default()
+                    }
+                ), downloads.first().unwrap().files);
+            }
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_default_to_the_http_download_manager() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  downloads:
+    - destination_folder: ~/Downloads
+      files:
+        - https://bartkessels.net/download/upset
+";
+        let file_path = write_test_file(&configuration, "downloads-default");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!("http".to_string(), result.configuration.downloads.unwrap().first().unwrap().download_manager);
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_extract_options_of_file_downloads() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  downloads:
+    - destination_folder: ~/Tools
+      files:
+        - url: https://bartkessels.net/download/upset.zip
+          extract: true
+        - url: https://bartkessels.net/download/it-depends.tar.gz
+          extract:
+            strip_components: 1
+            members:
+              - bin/it-depends
+";
+        let file_path = write_test_file(&configuration, "downloads-extract");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            let downloads = result.configuration.downloads.unwrap();
+            let files = &downloads.first().unwrap().files;
+
+            assert_eq!(Some(ExtractConfiguration::default()), files[0].extract);
+            assert_eq!(Some(ExtractConfiguration {
+                strip_components: 1,
+                members: vec!("bin/it-depends".to_string())
+            }), files[1].extract);
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_files_to_copy() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  files:
+    - source: ~/Git-projects/dotfiles/gitconfig
+      destination: ~/.gitconfig
+    - source: ~/Git-projects/dotfiles/ssh_config
+      destination: ~/.ssh/config
+      mode: 0600
+      overwrite: true
+      backup: true
+    - source: ~/Git-projects/dotfiles/env
+      destination: ~/.config/upset/
+      mode: \"0640\"
+";
+        let file_path = write_test_file(&configuration, "files");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            let files = result.configuration.files.unwrap();
+
+            assert_eq!(FileCopyConfiguration {
+                source: "~/Git-projects/dotfiles/gitconfig".to_string(),
+                destination: "~/.gitconfig".to_string(),
+                ..Default::default()
+            }, files[0]);
+            assert_eq!(FileCopyConfiguration {
+                source: "~/Git-projects/dotfiles/ssh_config".to_string(),
+                destination: "~/.ssh/config".to_string(),
+                mode: Some(0o600),
+                overwrite: true,
+                backup: true
+            }, files[1]);
+            assert_eq!(Some(0o640), files[2].mode);
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_mode_in_every_notation() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  files:
+    - source: a
+      destination: b
+      mode: 0644
+    - source: a
+      destination: b
+      mode: 644
+    - source: a
+      destination: b
+      mode: 0o644
+    - source: a
+      destination: b
+      mode: \"0755\"
+  directories:
+    - path: ~/.ssh
+      mode: 0o700
+    - path: ~/bin
+      mode: 755
+";
+        let file_path = write_test_file(&configuration, "modes");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            let modes: Vec<Option<u32>> = result.configuration.files.unwrap()
+                .iter()
+                .map(|file| file.mode)
+                .collect();
+            let directories = result.configuration.directories.unwrap();
+
+            assert_eq!(vec!(Some(0o644), Some(0o644), Some(0o644), Some(0o755)), modes);
+            assert_eq!(Some(0o700), directories[0].mode);
+            assert_eq!(Some(0o755), directories[1].mode);
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_reject_a_mode_which_is_not_octal() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  templates:
+    - source: a
+      destination: b
+      mode: 0o888
+";
+        let file_path = write_test_file(&configuration, "invalid-mode");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_err());
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_scripts() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  scripts:
+    - name: Install rust
+      run: curl https://sh.rustup.rs -sSf | sh -s -- -y
+      creates: ~/.cargo/bin/rustup
+    - path: ./install.py
+      interpreter: python
+      working_directory: ~/Git-projects/dotfiles
+      environment:
+        PROFILE: work
+      unless: test -f ~/.installed
+";
+        let file_path = write_test_file(&configuration, "scripts");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            let scripts = result.configuration.scripts.unwrap();
+
+            assert_eq!(ScriptConfiguration {
+                name: Some("Install rust".to_string()),
+                run: Some("curl https://sh.rustup.rs -sSf | sh -s -- -y".to_string()),
+                creates: Some("~/.cargo/bin/rustup".to_string()),
+                ..Default::default()
+            }, scripts[0]);
+            assert_eq!(ScriptConfiguration {
+                path: Some("./install.py".to_string()),
+                interpreter: Some("python".to_string()),
+                working_directory: Some("~/Git-projects/dotfiles".to_string()),
+                environment: BTreeMap::from([("PROFILE".to_string(), "work".to_string())]),
+                unless: Some("test -f ~/.installed".to_string()),
+                ..Default::default()
+            }, scripts[1]);
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_dotfiles() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  dotfiles:
+    - source_folder: ~/Git-projects/dotfiles
+      prune: true
+      links:
+        - .zshrc
+        - source: nvim
+          target: .config/nvim
+";
+        let file_path = write_test_file(&configuration, "dotfiles");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(DotfilesConfiguration {
+                source_folder: "~/Git-projects/dotfiles".to_string(),
+                target_folder: None,
+                links: vec!(
+                    DotfileLinkConfiguration::from(".zshrc".to_string()),
+                    DotfileLinkConfiguration {
+                        source: "nvim".to_string(),
+                        target: Some(".config/nvim".to_string())
+                    }
+                ),
+                prune: true
+            }), result.configuration.dotfiles.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_templates() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  templates:
+    - source: ~/Git-projects/dotfiles/gitconfig
+      destination: ~/.gitconfig
+      mode: 0644
+      variables:
+        email: bart@bartkessels.net
+";
+        let file_path = write_test_file(&configuration, "templates");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(TemplateConfiguration {
+                source: "~/Git-projects/dotfiles/gitconfig".to_string(),
+                destination: "~/.gitconfig".to_string(),
+                variables: BTreeMap::from([("email".to_string(), "bart@bartkessels.net".to_string())]),
+                mode: Some(0o644)
+            }), result.configuration.templates.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_directories() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  directories:
+    - ~/Git-repos
+    - path: ~/.ssh
+      mode: 0700
+      owner: bart:staff
+";
+        let file_path = write_test_file(&configuration, "directories");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(
+                DirectoryConfiguration::from("~/Git-repos".to_string()),
+                DirectoryConfiguration {
+                    path: "~/.ssh".to_string(),
+                    mode: Some(0o700),
+                    owner: Some("bart:staff".to_string())
+                }
+            ), result.configuration.directories.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_environment() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  environment:
+    variables:
+      EDITOR: nvim
+      GOPATH: ~/go
+    path:
+      - ~/bin
+";
+        let file_path = write_test_file(&configuration, "environment");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(EnvironmentConfiguration {
+                variables: BTreeMap::from([
+                    ("EDITOR".to_string(), "nvim".to_string()),
+                    ("GOPATH".to_string(), "~/go".to_string())
+                ]),
+                path: vec!("~/bin".to_string())
+            }, result.configuration.environment.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_lines_in_files() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  line_in_file:
+    - path: /etc/ssh/sshd_config
+      line: PasswordAuthentication no
+      regexp: ^#?PasswordAuthentication
+    - path: ~/.bashrc
+      name: aliases
+      block: alias ll='ls -l'
+      state: absent
+";
+        let file_path = write_test_file(&configuration, "line_in_file");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(
+                LineInFileConfiguration {
+                    path: "/etc/ssh/sshd_config".to_string(),
+                    line: Some("PasswordAuthentication no".to_string()),
+                    regexp: Some("^#?PasswordAuthentication".to_string()),
+                    ..Default::default()
+                },
+                LineInFileConfiguration {
+                    path: "~/.bashrc".to_string(),
+                    block: Some("alias ll='ls -l'".to_string()),
+                    name: Some("aliases".to_string()),
+                    state: LineState::Absent,
+                    ..Default::default()
+                }
+            ), result.configuration.line_in_file.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_ssh_configuration() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  ssh:
+    keys:
+      - type: ed25519
+        comment: bart@upset
+    hosts:
+      - host: github.com
+        user: git
+        port: 22
+    known_hosts:
+      - github.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl
+      - host: gitlab.com
+        fingerprint: SHA256:eUXGGm1YGsMAS7vkcx6JOJdOGHPem5gQp4taiCfCLB8
+";
+        let file_path = write_test_file(&configuration, "ssh");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(SshConfiguration {
+                keys: vec!(SshKeyConfiguration {
+                    key_type: Some("ed25519".to_string()),
+                    comment: Some("bart@upset".to_string()),
+                    ..Default::default()
+                }),
+                hosts: vec!(SshHostConfiguration {
+                    host: "github.com".to_string(),
+                    user: Some("git".to_string()),
+                    port: Some(22),
+                    ..Default::default()
+                }),
+                known_hosts: vec!(
+                    KnownHostConfiguration::from(
+                        "github.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl".to_string()
+                    ),
+                    KnownHostConfiguration {
+                        host: "gitlab.com".to_string(),
+                        fingerprint: Some("SHA256:eUXGGm1YGsMAS7vkcx6JOJdOGHPem5gQp4taiCfCLB8".to_string()),
+                        ..Default::default()
+                    }
+                )
+            }, result.configuration.ssh.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_services() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  services:
+    - name: syncthing.service
+      enabled: true
+      state: started
+    - name: backup.timer
+      scope: system
+      source: ~/Git-projects/dotfiles/backup.timer
+";
+        let file_path = write_test_file(&configuration, "services");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(
+                ServiceConfiguration {
+                    name: "syncthing.service".to_string(),
+                    enabled: Some(true),
+                    state: Some(ServiceState::Started),
+                    ..Default::default()
+                },
+                ServiceConfiguration {
+                    name: "backup.timer".to_string(),
+                    scope: ServiceScope::System,
+                    source: Some("~/Git-projects/dotfiles/backup.timer".to_string()),
+                    ..Default::default()
+                }
+            ), result.configuration.services.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_schedules() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  schedules:
+    - name: backup
+      schedule: 0 2 * * *
+      command: ~/bin/backup.sh
+";
+        let file_path = write_test_file(&configuration, "schedules");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(
+                ScheduleConfiguration {
+                    name: "backup".to_string(),
+                    schedule: "0 2 * * *".to_string(),
+                    command: "~/bin/backup.sh".to_string()
+                }
+            ), result.configuration.schedules.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_fonts() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  fonts:
+    - ~/Git-projects/dotfiles/fonts/Inter.ttf
+    - url: https://github.com/ryanoasis/nerd-fonts/releases/latest/download/FiraCode.zip
+      extract: true
+";
+        let file_path = write_test_file(&configuration, "fonts");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(
+                DownloadFileConfiguration::from("~/Git-projects/dotfiles/fonts/Inter.ttf".to_string()),
+                DownloadFileConfiguration {
+                    url: "https://github.com/ryanoasis/nerd-fonts/releases/latest/download/FiraCode.zip".to_string(),
+                    extract: Some(ExtractConfiguration::default()),
+                    ..Default::default()
+                }
+            ), result.configuration.fonts.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_runtimes() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  runtimes:
+    - version_manager: rustup
+      version: stable
+      default: true
+      components:
+        - clippy
+      targets:
+        - wasm32-unknown-unknown
+    - version_manager: mise
+      version: node@20
+";
+        let file_path = write_test_file(&configuration, "runtimes");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(
+                RuntimeConfiguration {
+                    version_manager: "rustup".to_string(),
+                    version: "stable".to_string(),
+                    default: true,
+                    components: vec!("clippy".to_string()),
+                    targets: vec!("wasm32-unknown-unknown".to_string())
+                },
+                RuntimeConfiguration {
+                    version_manager: "mise".to_string(),
+                    version: "node@20".to_string(),
+                    ..Default::default()
+                }
+            ), result.configuration.runtimes.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_container_images() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  container_images:
+    - container_engine: podman
+      images:
+        - postgres:16
+        - image: alpine
+          digest: sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b
+";
+        let file_path = write_test_file(&configuration, "container_images");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(
+                ContainerImagesConfiguration {
+                    container_engine: "podman".to_string(),
+                    images: vec!(
+                        ContainerImageConfiguration::from("postgres:16".to_string()),
+                        ContainerImageConfiguration {
+                            image: "alpine".to_string(),
+                            digest: Some("sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b".to_string())
+                        }
+                    )
+                }
+            ), result.configuration.container_images.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
+    #[test]
+    fn read_configuration_should_parse_the_groups() {
+        // Arrange
+        let configuration = "\
+version: 1.0
+configuration:
+  groups:
+    - dev
+    - name: docker
+      members:
+        - bart
+";
+        let file_path = write_test_file(&configuration, "groups");
+
+        // Act
+        let sut = YamlFileReader::new();
+        let result = sut.read_configuration(&file_path);
+
+        // Assert
+        assert!(result.is_ok());
+        if let Ok(result) = result {
+            assert_eq!(vec!(
+                GroupConfiguration::from("dev".to_string()),
+                GroupConfiguration {
+                    name: "docker".to_string(),
+                    members: vec!("bart".to_string())
+                }
+            ), result.configuration.groups.unwrap());
+        }
+
+        // Teardown
+        delete_test_file(&file_path);
+    }
+
     fn write_test_file(contents: &str, name: &str) -> String {
         let file_path = &format!("./{}.yml", &name);
         let mut file = File::create(file_path).expect("Unable to create test file");
@@ -0,0 +1,10 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::DownloadFileConfiguration;
+
+/// Public methods to call for installing fonts
+#[automock]
+pub trait FontInstaller {
+    fn install(&self, fonts: &Vec<DownloadFileConfiguration>);
+    fn as_any(&self) -> &dyn Any;
+}
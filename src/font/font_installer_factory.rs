@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::command::Command;
+use crate::commands::{FcCacheCommand, InterpreterCommand};
+use crate::file_download::FileDownloadFactory;
+use crate::font::font_installer::FontInstaller;
+use crate::font::user_font_installer::UserFontInstaller;
+use crate::path::home_directory;
+use crate::terminal::TerminalOutput;
+
+pub trait FontInstallerFactory {
+    fn get_font_installer(&self) -> Option<Arc<dyn FontInstaller>>;
+}
+
+pub struct FontInstallerFactoryImpl {
+    file_download_factory: Arc<dyn FileDownloadFactory>,
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl FontInstallerFactory for FontInstallerFactoryImpl {
+    /// Remote fonts are downloaded using the built-in download manager,
+    /// only Linux keeps a font cache which needs to be refreshed
+    ///
+    /// Windows only picks up fonts which are registered in the registry as well,
+    /// which is done with Windows PowerShell just like the environment variables
+    fn get_font_installer(&self) -> Option<Arc<dyn FontInstaller>> {
+        let font_folder = get_font_folder()?.to_string_lossy().to_string();
+        let file_download = self.file_download_factory.get_file_downloader("http", &font_folder)?;
+        let font_cache_command: Option<Arc<dyn Command>> = match cfg!(target_os = "linux") {
+            true => Some(FcCacheCommand::new()),
+            false => None
+        };
+        let powershell_command: Option<Arc<dyn Command>> = match cfg!(windows) {
+            true => Some(InterpreterCommand::new("powershell", &None, &BTreeMap::new())),
+            false => None
+        };
+
+        return Some(UserFontInstaller::new(
+            &file_download,
+            &font_cache_command,
+            &powershell_command,
+            &font_folder,
+            &self.terminal_output
+        ));
+    }
+}
+
+impl FontInstallerFactoryImpl {
+    pub fn new(
+        file_download_factory: &Arc<dyn FileDownloadFactory>,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn FontInstallerFactory> {
+        return Arc::new(Self {
+            file_download_factory: file_download_factory.clone(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+/// Get the folder containing the fonts of the current user
+fn get_font_folder() -> Option<PathBuf> {
+    if cfg!(windows) {
+        let local_app_data = env::var("LOCALAPPDATA")
+            .ok()
+            .map(PathBuf::from)
+            .or_else(|| home_directory().map(|home| Path::new(&home).join("AppData").join("Local")))?;
+
+        return Some(local_app_data.join("Microsoft").join("Windows").join("Fonts"));
+    }
+
+    let home = PathBuf::from(home_directory()?);
+
+    return match cfg!(target_os = "macos") {
+        true => Some(home.join("Library/Fonts")),
+        false => Some(home.join(".local/share/fonts"))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::file_download::{FileDownload, FileDownloadFactory, MockFileDownload};
+    use crate::font::font_installer_factory::FontInstallerFactoryImpl;
+    use crate::font::user_font_installer::UserFontInstaller;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    struct MockFileDownloadFactory;
+
+    impl FileDownloadFactory for MockFileDownloadFactory {
+        fn get_file_downloader(&self, name: &str, _: &str) -> Option<Arc<dyn FileDownload>> {
+            return match name {
+                "http" => Some(Arc::new(MockFileDownload::new())),
+                _ => None
+            };
+        }
+    }
+
+    #[test]
+    fn get_font_installer_returns_user_font_installer() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = FontInstallerFactoryImpl::new(
+            &(Arc::new(MockFileDownloadFactory) as Arc<dyn FileDownloadFactory>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_font_installer();
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<UserFontInstaller>());
+    }
+}
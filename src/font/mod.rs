@@ -0,0 +1,8 @@
+mod font_installer;
+mod user_font_installer;
+mod font_installer_factory;
+
+pub use font_installer::MockFontInstaller;
+pub use font_installer::FontInstaller;
+pub use font_installer_factory::FontInstallerFactory;
+pub use font_installer_factory::FontInstallerFactoryImpl;
\ No newline at end of file
@@ -0,0 +1,367 @@
+use std::any::Any;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::DownloadFileConfiguration;
+use crate::file_download::FileDownload;
+use crate::font::font_installer::FontInstaller;
+use crate::path::expand_home_directory;
+use crate::terminal::TerminalOutput;
+
+/// Outcome of installing a single local font
+#[derive(Debug, PartialEq)]
+enum InstallResult {
+    Installed,
+    UpToDate
+}
+
+/// Installs fonts into the font folder of the current user
+pub struct UserFontInstaller {
+    /// Download manager which downloads the remote fonts into the font folder
+    file_download: Arc<dyn FileDownload>,
+
+    /// Command to refresh the font cache, when the operating system has one
+    font_cache_command: Option<Arc<dyn Command>>,
+
+    /// Windows PowerShell command which registers the fonts, only used on Windows
+    powershell_command: Option<Arc<dyn Command>>,
+
+    /// Folder containing the fonts of the user, e.g. `~/.local/share/fonts`
+    font_folder: String,
+
+    /// Terminal output where the installation status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl FontInstaller for UserFontInstaller {
+    fn install(&self, fonts: &Vec<DownloadFileConfiguration>) {
+        let (remote_fonts, local_fonts): (Vec<DownloadFileConfiguration>, Vec<DownloadFileConfiguration>) = fonts
+            .iter()
+            .cloned()
+            .partition(|font| is_remote(&font));
+
+        for font in &local_fonts {
+            self.install_local_font(&font.url);
+        }
+
+        if !remote_fonts.is_empty() {
+            self.file_download.download(&remote_fonts);
+        }
+
+        self.refresh_font_cache();
+        self.register_fonts();
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl UserFontInstaller {
+    pub fn new(
+        file_download: &Arc<dyn FileDownload>,
+        font_cache_command: &Option<Arc<dyn Command>>,
+        powershell_command: &Option<Arc<dyn Command>>,
+        font_folder: &str,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn FontInstaller> {
+        return Arc::new(Self {
+            file_download: file_download.clone(),
+            font_cache_command: font_cache_command.clone(),
+            powershell_command: powershell_command.clone(),
+            font_folder: expand_home_directory(&font_folder),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn install_local_font(&self, path: &str) {
+        let source = PathBuf::from(expand_home_directory(&path));
+
+        self.terminal_output.loading(&format!("Installing {}", &path));
+
+        match install(&source, Path::new(&self.font_folder)) {
+            Ok(InstallResult::Installed) => self.terminal_output.finish_with_success(
+                &format!("Successfully installed {}", &path)
+            ),
+            Ok(InstallResult::UpToDate) => self.terminal_output.finish_with_success(
+                &format!("{} is up to date", &path)
+            ),
+            Err(error) => self.terminal_output.finish_with_warning(
+                &format!("Unable to install {}, {}", &path, &error)
+            )
+        }
+    }
+
+    fn register_fonts(&self) {
+        let Some(powershell_command) = &self.powershell_command else {
+            return;
+        };
+
+        self.terminal_output.loading(&"Registering the fonts".to_string());
+
+        let result = powershell_command.execute(&[
+            "-NoProfile".to_string(),
+            "-NonInteractive".to_string(),
+            "-Command".to_string(),
+            create_registration_script(&self.font_folder)
+        ]);
+
+        match result {
+            Ok(true) => self.terminal_output.finish_with_success(&"Successfully registered the fonts".to_string()),
+            _ => self.terminal_output.finish_with_warning(&"Unable to register the fonts".to_string())
+        }
+    }
+
+    fn refresh_font_cache(&self) {
+        let Some(font_cache_command) = &self.font_cache_command else {
+            return;
+        };
+
+        self.terminal_output.loading(&"Refreshing the font cache".to_string());
+
+        match font_cache_command.execute(&["-f".to_string(), self.font_folder.to_string()]) {
+            Ok(true) => self.terminal_output.finish_with_success(&"Successfully refreshed the font cache".to_string()),
+            _ => self.terminal_output.finish_with_warning(&"Unable to refresh the font cache".to_string())
+        }
+    }
+}
+
+/// Create the script registering every font in the font folder for the current user,
+/// Windows only picks up fonts which are listed in the registry
+fn create_registration_script(font_folder: &str) -> String {
+    return format!(
+        "$key = 'HKCU:\\Software\\Microsoft\\Windows NT\\CurrentVersion\\Fonts'; \
+        if (-not (Test-Path -Path $key)) {{ New-Item -Path $key | Out-Null }}; \
+        Get-ChildItem -LiteralPath {} -File | Where-Object {{ $_.Extension -in '.ttf', '.ttc', '.otf' }} | ForEach-Object {{ \
+        $type = if ($_.Extension -eq '.otf') {{ 'OpenType' }} else {{ 'TrueType' }}; \
+        New-ItemProperty -Path $key -Name \"$($_.BaseName) ($type)\" -Value $_.FullName -PropertyType String -Force | Out-Null }}",
+        quote(&font_folder)
+    );
+}
+
+/// Quote the value as a literal PowerShell string
+fn quote(value: &str) -> String {
+    return format!("'{}'", value.replace('\'', "''"));
+}
+
+/// Fonts from a URL or GitHub release are downloaded, all other fonts are local files
+fn is_remote(font: &DownloadFileConfiguration) -> bool {
+    return font.github_release.is_some() || font.url.contains("://");
+}
+
+/// Copy the font into the font folder, unless it's already installed
+fn install(source: &Path, font_folder: &Path) -> io::Result<InstallResult> {
+    let contents = fs::read(&source)?;
+    let destination = font_folder.join(source.file_name().unwrap_or_default());
+
+    if fs::read(&destination).is_ok_and(|existing| existing == contents) {
+        return Ok(InstallResult::UpToDate);
+    }
+
+    fs::create_dir_all(&font_folder)?;
+    fs::write(&destination, &contents)?;
+
+    return Ok(InstallResult::Installed);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::DownloadFileConfiguration;
+    use crate::file_download::{FileDownload, MockFileDownload};
+    use crate::font::font_installer::FontInstaller;
+    use crate::font::user_font_installer::{create_registration_script, UserFontInstaller};
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    #[test]
+    fn install_copies_the_local_fonts_into_the_font_folder() {
+        // Arrange
+        let folder = create_test_folder("font", "local");
+        let source = folder.join("FiraCode-Regular.ttf");
+        let font_folder = folder.join("fonts");
+        let fonts = vec!(DownloadFileConfiguration::from(source.to_string_lossy().to_string()));
+        let mut file_download_mock = MockFileDownload::new();
+        fs::write(&source, "font").unwrap();
+
+        // Setup the expectation
+        file_download_mock.expect_download().never();
+
+        // Act
+        let sut = create_font_installer(&font_folder, file_download_mock, None, setup_terminal_output());
+        sut.install(&fonts);
+
+        // Assert
+        assert_eq!("font", fs::read_to_string(font_folder.join("FiraCode-Regular.ttf")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn install_downloads_the_remote_fonts() {
+        // Arrange
+        let folder = create_test_folder("font", "remote");
+        let fonts = vec!(DownloadFileConfiguration::from(
+            "https://github.com/ryanoasis/nerd-fonts/releases/latest/download/FiraCode.zip".to_string()
+        ));
+        let expected_fonts = fonts.clone();
+        let mut file_download_mock = MockFileDownload::new();
+
+        // Setup the expectation
+        file_download_mock.expect_download()
+            .withf(move |args| args.eq(&expected_fonts))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = create_font_installer(&folder, file_download_mock, None, setup_terminal_output());
+        sut.install(&fonts);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn install_calls_finish_with_success_when_the_font_is_up_to_date() {
+        // Arrange
+        let folder = create_test_folder("font", "up-to-date");
+        let source = folder.join("FiraCode-Regular.ttf");
+        let font_folder = folder.join("fonts");
+        let fonts = vec!(DownloadFileConfiguration::from(source.to_string_lossy().to_string()));
+        let expected_message = format!("{} is up to date", &source.to_string_lossy());
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(&source, "font").unwrap();
+        fs::create_dir_all(&font_folder).unwrap();
+        fs::write(font_folder.join("FiraCode-Regular.ttf"), "font").unwrap();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(move |message| message == &expected_message)
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = create_font_installer(
+            &font_folder,
+            MockFileDownload::new(),
+            None,
+            Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>
+        );
+        sut.install(&fonts);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn install_refreshes_the_font_cache() {
+        // Arrange
+        let folder = create_test_folder("font", "cache");
+        let expected_folder = folder.to_string_lossy().to_string();
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(move |args| args == &["-f".to_string(), expected_folder.clone()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = create_font_installer(
+            &folder,
+            MockFileDownload::new(),
+            Some(Arc::new(command_mock) as Arc<dyn Command>),
+            setup_terminal_output()
+        );
+        sut.install(&vec!());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn install_registers_the_fonts_with_powershell() {
+        // Arrange
+        let folder = create_test_folder("font", "register");
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args[2] == "-Command" && args[3].contains("New-ItemProperty -Path $key"))
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = UserFontInstaller::new(
+            &(Arc::new(MockFileDownload::new()) as Arc<dyn FileDownload>),
+            &None,
+            &Some(Arc::new(command_mock) as Arc<dyn Command>),
+            &folder.to_string_lossy(),
+            &setup_terminal_output()
+        );
+        sut.install(&vec!());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn create_registration_script_registers_the_fonts_of_the_folder_for_the_user() {
+        // Act
+        let result = create_registration_script("C:\\Users\\bart\\AppData\\Local\\Microsoft\\Windows\\Fonts");
+
+        // Assert
+        assert!(result.starts_with("$key = 'HKCU:\\Software\\Microsoft\\Windows NT\\CurrentVersion\\Fonts';"));
+        assert!(result.contains("Get-ChildItem -LiteralPath 'C:\\Users\\bart\\AppData\\Local\\Microsoft\\Windows\\Fonts' -File"));
+    }
+
+    #[test]
+    fn install_calls_finish_with_warning_when_the_local_font_does_not_exist() {
+        // Arrange
+        let folder = create_test_folder("font", "missing");
+        let fonts = vec!(DownloadFileConfiguration::from(
+            folder.join("missing.ttf").to_string_lossy().to_string()
+        ));
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = create_font_installer(
+            &folder,
+            MockFileDownload::new(),
+            None,
+            Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>
+        );
+        sut.install(&fonts);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn create_font_installer(
+        font_folder: &PathBuf,
+        file_download_mock: MockFileDownload,
+        font_cache_command: Option<Arc<dyn Command>>,
+        terminal_output: Arc<dyn TerminalOutput>
+    ) -> Arc<dyn FontInstaller> {
+        return UserFontInstaller::new(
+            &(Arc::new(file_download_mock) as Arc<dyn FileDownload>),
+            &font_cache_command,
+            &None,
+            &font_folder.to_string_lossy(),
+            &terminal_output
+        );
+    }
+}
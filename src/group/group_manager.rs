@@ -0,0 +1,10 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::GroupConfiguration;
+
+/// Public methods to call for managing groups and their members
+#[automock]
+pub trait GroupManager {
+    fn configure(&self, groups: &Vec<GroupConfiguration>);
+    fn as_any(&self) -> &dyn Any;
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use crate::commands::{GetentCommand, GroupaddCommand, IdCommand, UsermodCommand};
+use crate::group::group_manager::GroupManager;
+use crate::group::unix_group_manager::UnixGroupManager;
+use crate::terminal::TerminalOutput;
+
+pub trait GroupManagerFactory {
+    fn get_group_manager(&self) -> Option<Arc<dyn GroupManager>>;
+}
+
+pub struct GroupManagerFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl GroupManagerFactory for GroupManagerFactoryImpl {
+    /// Groups are managed using groupadd and usermod, which are only available on Linux
+    fn get_group_manager(&self) -> Option<Arc<dyn GroupManager>> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+
+        return Some(UnixGroupManager::new(
+            &GetentCommand::new(),
+            &IdCommand::new(),
+            &GroupaddCommand::new(),
+            &UsermodCommand::new(),
+            &self.terminal_output
+        ));
+    }
+}
+
+impl GroupManagerFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn GroupManagerFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::group::group_manager_factory::GroupManagerFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn get_group_manager_returns_unix_group_manager() {
+        use crate::group::unix_group_manager::UnixGroupManager;
+
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = GroupManagerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_group_manager();
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<UnixGroupManager>());
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn get_group_manager_returns_none_outside_of_linux() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = GroupManagerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_group_manager();
+
+        // Assert
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,8 @@
+mod group_manager;
+mod unix_group_manager;
+mod group_manager_factory;
+
+pub use group_manager::MockGroupManager;
+pub use group_manager::GroupManager;
+pub use group_manager_factory::GroupManagerFactory;
+pub use group_manager_factory::GroupManagerFactoryImpl;
\ No newline at end of file
@@ -0,0 +1,290 @@
+use std::any::Any;
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::GroupConfiguration;
+use crate::group::group_manager::GroupManager;
+use crate::terminal::TerminalOutput;
+
+/// Outcome of configuring a single group
+#[derive(Debug, PartialEq)]
+enum GroupResult {
+    Changed,
+    UpToDate
+}
+
+pub struct UnixGroupManager {
+    /// The getent command, used to check whether a group exists
+    getent_command: Arc<dyn Command>,
+
+    /// The id command, used to get the groups of a user
+    id_command: Arc<dyn Command>,
+
+    /// The groupadd command
+    groupadd_command: Arc<dyn Command>,
+
+    /// The usermod command
+    usermod_command: Arc<dyn Command>,
+
+    /// Terminal output where the status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl GroupManager for UnixGroupManager {
+    fn configure(&self, groups: &Vec<GroupConfiguration>) {
+        for group in groups {
+            self.configure_group(&group);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl UnixGroupManager {
+    pub fn new(
+        getent_command: &Arc<dyn Command>,
+        id_command: &Arc<dyn Command>,
+        groupadd_command: &Arc<dyn Command>,
+        usermod_command: &Arc<dyn Command>,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn GroupManager> {
+        return Arc::new(Self {
+            getent_command: getent_command.clone(),
+            id_command: id_command.clone(),
+            groupadd_command: groupadd_command.clone(),
+            usermod_command: usermod_command.clone(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn configure_group(&self, group: &GroupConfiguration) {
+        self.terminal_output.loading(&format!("Configuring group {}", &group.name));
+
+        match self.apply(&group) {
+            Ok(GroupResult::Changed) => self.terminal_output.finish_with_success(
+                &format!("Successfully configured group {}", &group.name)
+            ),
+            Ok(GroupResult::UpToDate) => self.terminal_output.finish_with_success(
+                &format!("Group {} is up to date", &group.name)
+            ),
+            Err(error) => self.terminal_output.finish_with_warning(
+                &format!("Unable to configure group {}, {}", &group.name, &error)
+            )
+        }
+    }
+
+    /// Create the group and add the missing members, the privileges
+    /// are only required when something needs to be changed
+    fn apply(&self, group: &GroupConfiguration) -> Result<GroupResult, String> {
+        let exists = self.getent_command
+            .output(&["group".to_string(), group.name.to_string()])
+            .is_ok();
+        let missing_members: Vec<&String> = group.members
+            .iter()
+            .filter(|member| !self.is_member(&member, &group.name))
+            .collect();
+
+        if exists && missing_members.is_empty() {
+            return Ok(GroupResult::UpToDate);
+        }
+
+        if !self.is_privileged() {
+            return Err("root privileges are required".to_string());
+        }
+
+        if !exists && !self.groupadd_command.execute(&[group.name.to_string()])? {
+            return Err("groupadd failed".to_string());
+        }
+
+        for member in missing_members {
+            let arguments = ["-aG".to_string(), group.name.to_string(), member.to_string()];
+
+            if !self.usermod_command.execute(&arguments)? {
+                return Err(format!("unable to add {}", &member));
+            }
+        }
+
+        return Ok(GroupResult::Changed);
+    }
+
+    fn is_member(&self, user: &str, group: &str) -> bool {
+        return self.id_command
+            .output(&["-nG".to_string(), user.to_string()])
+            .is_ok_and(|groups| groups.split_whitespace().any(|name| name == group));
+    }
+
+    fn is_privileged(&self) -> bool {
+        return self.id_command
+            .output(&["-u".to_string()])
+            .is_ok_and(|id| id.trim() == "0");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::GroupConfiguration;
+    use crate::group::group_manager::GroupManager;
+    use crate::group::unix_group_manager::UnixGroupManager;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output;
+
+    #[test]
+    fn configure_creates_the_group_and_adds_the_members() {
+        // Arrange
+        let groups = vec!(create_group());
+        let mut getent_mock = MockCommand::new();
+        let mut groupadd_mock = MockCommand::new();
+        let mut usermod_mock = MockCommand::new();
+
+        // Setup the expectation
+        getent_mock.expect_output().returning(|_| Err(String::default()));
+        groupadd_mock.expect_execute()
+            .withf(|args| args == &["dev".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+        usermod_mock.expect_execute()
+            .withf(|args| args == &["-aG".to_string(), "dev".to_string(), "bart".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = create_group_manager(
+            getent_mock,
+            setup_id_mock("bart wheel", "0"),
+            groupadd_mock,
+            usermod_mock,
+            setup_terminal_output()
+        );
+        sut.configure(&groups);
+    }
+
+    #[test]
+    fn configure_only_adds_the_members_when_the_group_exists() {
+        // Arrange
+        let groups = vec!(create_group());
+        let mut getent_mock = MockCommand::new();
+        let mut groupadd_mock = MockCommand::new();
+        let mut usermod_mock = MockCommand::new();
+
+        // Setup the expectation
+        getent_mock.expect_output()
+            .withf(|args| args == &["group".to_string(), "dev".to_string()])
+            .returning(|_| Ok("dev:x:1001:".to_string()));
+        groupadd_mock.expect_execute().never();
+        usermod_mock.expect_execute()
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = create_group_manager(
+            getent_mock,
+            setup_id_mock("bart", "0"),
+            groupadd_mock,
+            usermod_mock,
+            setup_terminal_output()
+        );
+        sut.configure(&groups);
+    }
+
+    #[test]
+    fn configure_calls_finish_with_success_when_the_group_is_up_to_date() {
+        // Arrange
+        let groups = vec!(create_group());
+        let mut getent_mock = MockCommand::new();
+        let mut groupadd_mock = MockCommand::new();
+        let mut usermod_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        getent_mock.expect_output().returning(|_| Ok("dev:x:1001:bart".to_string()));
+        groupadd_mock.expect_execute().never();
+        usermod_mock.expect_execute().never();
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message == "Group dev is up to date")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = create_group_manager(
+            getent_mock,
+            setup_id_mock("bart dev", "1000"),
+            groupadd_mock,
+            usermod_mock,
+            Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>
+        );
+        sut.configure(&groups);
+    }
+
+    #[test]
+    fn configure_calls_finish_with_warning_when_not_running_as_root() {
+        // Arrange
+        let groups = vec!(create_group());
+        let mut getent_mock = MockCommand::new();
+        let mut groupadd_mock = MockCommand::new();
+        let mut usermod_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        getent_mock.expect_output().returning(|_| Err(String::default()));
+        groupadd_mock.expect_execute().never();
+        usermod_mock.expect_execute().never();
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message == "Unable to configure group dev, root privileges are required")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = create_group_manager(
+            getent_mock,
+            setup_id_mock("bart", "1000"),
+            groupadd_mock,
+            usermod_mock,
+            Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>
+        );
+        sut.configure(&groups);
+    }
+
+    fn create_group() -> GroupConfiguration {
+        return GroupConfiguration {
+            name: "dev".to_string(),
+            members: vec!("bart".to_string())
+        };
+    }
+
+    fn create_group_manager(
+        getent_mock: MockCommand,
+        id_mock: MockCommand,
+        groupadd_mock: MockCommand,
+        usermod_mock: MockCommand,
+        terminal_output: Arc<dyn TerminalOutput>
+    ) -> Arc<dyn GroupManager> {
+        return UnixGroupManager::new(
+            &(Arc::new(getent_mock) as Arc<dyn Command>),
+            &(Arc::new(id_mock) as Arc<dyn Command>),
+            &(Arc::new(groupadd_mock) as Arc<dyn Command>),
+            &(Arc::new(usermod_mock) as Arc<dyn Command>),
+            &terminal_output
+        );
+    }
+
+    /// Setup the id command returning the groups of the user and the id of the current user
+    fn setup_id_mock(groups: &str, user_id: &str) -> MockCommand {
+        let mut id_mock = MockCommand::new();
+        let groups = groups.to_string();
+        let user_id = user_id.to_string();
+
+        // Setup the expectation
+        id_mock.expect_output()
+            .returning(move |args| match args[0].as_str() {
+                "-u" => Ok(format!("{}\n", &user_id)),
+                _ => Ok(format!("{}\n", &groups))
+            });
+
+        return id_mock;
+    }
+}
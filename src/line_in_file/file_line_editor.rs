@@ -0,0 +1,316 @@
+use std::any::Any;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use regex::Regex;
+use crate::configuration::{LineInFileConfiguration, LineState};
+use crate::line_in_file::line_editor::LineEditor;
+use crate::managed_block::set_managed_block;
+use crate::path::expand_home_directory;
+use crate::terminal::TerminalOutput;
+
+const DEFAULT_BLOCK_NAME: &str = "block";
+const DEFAULT_COMMENT: &str = "#";
+
+pub struct FileLineEditor {
+    /// Terminal output where the edit status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl LineEditor for FileLineEditor {
+    fn edit(&self, lines: &Vec<LineInFileConfiguration>) {
+        for line in lines {
+            self.edit_file(&line);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl FileLineEditor {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn LineEditor> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn edit_file(&self, line: &LineInFileConfiguration) {
+        let path = PathBuf::from(expand_home_directory(&line.path));
+
+        self.terminal_output.loading(&format!("Editing {}", &line.path));
+
+        match edit(&path, &line) {
+            Ok(true) => self.terminal_output.finish_with_success(
+                &format!("Successfully changed {}", &line.path)
+            ),
+            Ok(false) => self.terminal_output.finish_with_success(
+                &format!("{} is unchanged", &line.path)
+            ),
+            Err(error) => self.terminal_output.finish_with_warning(
+                &format!("Unable to edit {}, {}", &line.path, &error)
+            )
+        }
+    }
+}
+
+/// Edit the file, which is only written when its contents have changed
+///
+/// Returns whether the file has been changed
+fn edit(path: &Path, line: &LineInFileConfiguration) -> Result<bool, String> {
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.to_string())
+    };
+
+    let updated = match (&line.line, &line.block) {
+        (Some(_), Some(_)) => return Err("either a line or a block can be configured".to_string()),
+        (Some(_), None) => edit_line(&contents, &line)?,
+        (None, Some(block)) => Some(edit_block(&contents, &block, &line)),
+        (None, None) if line.regexp.is_some() && line.state == LineState::Absent => edit_line(&contents, &line)?,
+        (None, None) => return Err("a line, a block or a regexp to remove is required".to_string())
+    };
+
+    return match updated {
+        Some(updated) if updated != contents => {
+            fs::write(&path, &updated).map_err(|e| e.to_string())?;
+            Ok(true)
+        },
+        _ => Ok(false)
+    };
+}
+
+/// Make sure the line is present in, or absent from, the contents
+///
+/// Returns nothing when the contents don't have to change
+fn edit_line(contents: &str, line: &LineInFileConfiguration) -> Result<Option<String>, String> {
+    let expected = line.line.clone().unwrap_or_default();
+    let regexp = match &line.regexp {
+        Some(regexp) => Some(Regex::new(&regexp).map_err(|e| e.to_string())?),
+        None => None
+    };
+    let is_match = |existing: &str| match &regexp {
+        Some(regexp) => regexp.is_match(&existing),
+        None => existing == expected
+    };
+    let mut lines: Vec<String> = contents.lines().map(|existing| existing.to_string()).collect();
+
+    match line.state {
+        LineState::Present => {
+            match lines.iter().rposition(|existing| is_match(&existing)) {
+                Some(index) if lines[index] == expected => return Ok(None),
+                Some(index) => lines[index] = expected,
+                None if lines.contains(&expected) => return Ok(None),
+                None => lines.push(expected)
+            }
+        },
+        LineState::Absent => {
+            let count = lines.len();
+            lines.retain(|existing| !is_match(&existing));
+
+            if lines.len() == count {
+                return Ok(None);
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        return Ok(Some(String::new()));
+    }
+
+    return Ok(Some(lines.join("\n") + "\n"));
+}
+
+/// Make sure the block is present in, or absent from, the contents
+fn edit_block(contents: &str, block: &str, line: &LineInFileConfiguration) -> String {
+    let block = match line.state {
+        LineState::Present => Some(block),
+        LineState::Absent => None
+    };
+
+    return set_managed_block(
+        &contents,
+        line.comment.as_deref().unwrap_or(DEFAULT_COMMENT),
+        line.name.as_deref().unwrap_or(DEFAULT_BLOCK_NAME),
+        block
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+    use crate::configuration::{LineInFileConfiguration, LineState};
+    use crate::line_in_file::file_line_editor::{edit_line, FileLineEditor};
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    #[test]
+    fn edit_line_appends_the_line_when_it_is_missing() {
+        // Arrange
+        let line = create_line("127.0.0.1 upset.local", None, LineState::Present);
+
+        // Act
+        let result = edit_line("127.0.0.1 localhost\n", &line);
+
+        // Assert
+        assert_eq!(Ok(Some("127.0.0.1 localhost\n127.0.0.1 upset.local\n".to_string())), result);
+    }
+
+    #[test]
+    fn edit_line_returns_none_when_the_line_is_present() {
+        // Arrange
+        let line = create_line("127.0.0.1 localhost", None, LineState::Present);
+
+        // Act
+        let result = edit_line("127.0.0.1 localhost\n::1 localhost\n", &line);
+
+        // Assert
+        assert_eq!(Ok(None), result);
+    }
+
+    #[test]
+    fn edit_line_replaces_the_last_line_matching_the_regexp() {
+        // Arrange
+        let line = create_line("PasswordAuthentication no", Some("^#?PasswordAuthentication"), LineState::Present);
+
+        // Act
+        let result = edit_line("#PasswordAuthentication yes\nPasswordAuthentication yes\nPort 22\n", &line);
+
+        // Assert
+        assert_eq!(
+            Ok(Some("#PasswordAuthentication yes\nPasswordAuthentication no\nPort 22\n".to_string())),
+            result
+        );
+    }
+
+    #[test]
+    fn edit_line_removes_all_lines_matching_the_regexp() {
+        // Arrange
+        let line = create_line("", Some("upset\\.local$"), LineState::Absent);
+
+        // Act
+        let result = edit_line("127.0.0.1 upset.local\n127.0.0.1 localhost\n::1 upset.local\n", &line);
+
+        // Assert
+        assert_eq!(Ok(Some("127.0.0.1 localhost\n".to_string())), result);
+    }
+
+    #[test]
+    fn edit_line_returns_none_when_the_line_is_absent() {
+        // Arrange
+        let line = create_line("127.0.0.1 upset.local", None, LineState::Absent);
+
+        // Act
+        let result = edit_line("127.0.0.1 localhost\n", &line);
+
+        // Assert
+        assert_eq!(Ok(None), result);
+    }
+
+    #[test]
+    fn edit_line_returns_error_for_an_invalid_regexp() {
+        // Arrange
+        let line = create_line("upset", Some("(upset"), LineState::Present);
+
+        // Act
+        let result = edit_line("", &line);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn edit_writes_the_block_between_the_marker_comments() {
+        // Arrange
+        let folder = create_test_folder("line-in-file", "block");
+        let lines = vec!(LineInFileConfiguration {
+            path: folder.join(".bashrc").to_string_lossy().to_string(),
+            block: Some("alias ll='ls -l'".to_string()),
+            name: Some("aliases".to_string()),
+            ..Default::default()
+        });
+        fs::write(folder.join(".bashrc"), "export EDITOR=nvim\n").unwrap();
+
+        // Act
+        let sut = FileLineEditor::new(&setup_terminal_output());
+        sut.edit(&lines);
+
+        // Assert
+        assert_eq!(
+            "export EDITOR=nvim\n# BEGIN upset aliases\nalias ll='ls -l'\n# END upset aliases\n",
+            fs::read_to_string(folder.join(".bashrc")).unwrap()
+        );
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn edit_calls_finish_with_success_when_the_file_is_unchanged() {
+        // Arrange
+        let folder = create_test_folder("line-in-file", "unchanged");
+        let lines = vec!(LineInFileConfiguration {
+            path: folder.join("hosts").to_string_lossy().to_string(),
+            ..create_line("127.0.0.1 localhost", None, LineState::Present)
+        });
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(folder.join("hosts"), "127.0.0.1 localhost").unwrap();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is unchanged"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = FileLineEditor::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.edit(&lines);
+
+        // Assert
+        assert_eq!("127.0.0.1 localhost", fs::read_to_string(folder.join("hosts")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn edit_calls_finish_with_warning_without_a_line_or_a_block() {
+        // Arrange
+        let folder = create_test_folder("line-in-file", "empty");
+        let lines = vec!(LineInFileConfiguration {
+            path: folder.join("hosts").to_string_lossy().to_string(),
+            ..Default::default()
+        });
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = FileLineEditor::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.edit(&lines);
+
+        // Assert
+        assert!(!folder.join("hosts").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn create_line(line: &str, regexp: Option<&str>, state: LineState) -> LineInFileConfiguration {
+        return LineInFileConfiguration {
+            line: Some(line.to_string()),
+            regexp: regexp.map(|regexp| regexp.to_string()),
+            state,
+            ..Default::default()
+        };
+    }
+}
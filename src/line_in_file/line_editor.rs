@@ -0,0 +1,10 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::LineInFileConfiguration;
+
+/// Public methods to call for editing lines in files
+#[automock]
+pub trait LineEditor {
+    fn edit(&self, lines: &Vec<LineInFileConfiguration>);
+    fn as_any(&self) -> &dyn Any;
+}
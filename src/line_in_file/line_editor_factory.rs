@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use crate::line_in_file::file_line_editor::FileLineEditor;
+use crate::line_in_file::line_editor::LineEditor;
+use crate::terminal::TerminalOutput;
+
+pub trait LineEditorFactory {
+    fn get_line_editor(&self) -> Arc<dyn LineEditor>;
+}
+
+pub struct LineEditorFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl LineEditorFactory for LineEditorFactoryImpl {
+    fn get_line_editor(&self) -> Arc<dyn LineEditor> {
+        return FileLineEditor::new(&self.terminal_output);
+    }
+}
+
+impl LineEditorFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn LineEditorFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::line_in_file::file_line_editor::FileLineEditor;
+    use crate::line_in_file::line_editor_factory::LineEditorFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[test]
+    fn get_line_editor_returns_file_line_editor() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = LineEditorFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_line_editor();
+
+        // Assert
+        assert!(result.as_any().is::<FileLineEditor>());
+    }
+}
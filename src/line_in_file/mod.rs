@@ -0,0 +1,8 @@
+mod line_editor;
+mod file_line_editor;
+mod line_editor_factory;
+
+pub use line_editor::MockLineEditor;
+pub use line_editor::LineEditor;
+pub use line_editor_factory::LineEditorFactory;
+pub use line_editor_factory::LineEditorFactoryImpl;
\ No newline at end of file
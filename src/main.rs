@@ -6,14 +6,46 @@ mod command;
 mod commands;
 mod arguments;
 mod file_download;
+mod file_copy;
+mod dotfiles;
 mod terminal;
 mod parser;
+mod path;
+mod script;
+mod template;
+mod directory;
+mod environment;
+mod line_in_file;
+mod ssh;
+mod service;
+mod schedule;
+mod font;
+mod runtime;
+mod container;
+mod group;
+mod managed_block;
+#[cfg(test)]
+mod test_support;
 
 use clap::Parser;
+use crate::container::ContainerEngineFactoryImpl;
+use crate::directory::DirectoryCreatorFactoryImpl;
+use crate::dotfiles::DotfileManagerFactoryImpl;
+use crate::environment::EnvironmentManagerFactoryImpl;
+use crate::file_copy::FileCopyFactoryImpl;
 use crate::file_download::FileDownloadFactoryImpl;
 use crate::file_reader::YamlFileReader;
+use crate::font::FontInstallerFactoryImpl;
+use crate::group::GroupManagerFactoryImpl;
+use crate::line_in_file::LineEditorFactoryImpl;
 use crate::package_manager::PackageManagerFactoryImpl;
-use crate::parser::ParserFactory;
+use crate::parser::{ParserFactories, ParserFactory};
+use crate::runtime::VersionManagerFactoryImpl;
+use crate::schedule::SchedulerFactoryImpl;
+use crate::script::ScriptRunnerFactoryImpl;
+use crate::service::ServiceManagerFactoryImpl;
+use crate::ssh::SshManagerFactoryImpl;
+use crate::template::TemplateRendererFactoryImpl;
 use crate::terminal::{TerminalOutputFactory, TerminalOutputFactoryImpl};
 use crate::terminal::TerminalOutputType::Spinner;
 use crate::version_control::VersionControlSystemFactoryImpl;
@@ -27,11 +59,39 @@ fn main() {
     let package_manager_factory = PackageManagerFactoryImpl::new(&terminal_output);
     let version_control_system_factory = VersionControlSystemFactoryImpl::new(&terminal_output);
     let file_download_factory = FileDownloadFactoryImpl::new(&terminal_output);
-    let parser_factory = ParserFactory::new(
-        &package_manager_factory,
-        &version_control_system_factory,
-        &file_download_factory
-    );
+    let file_copy_factory = FileCopyFactoryImpl::new(&terminal_output);
+    let script_runner_factory = ScriptRunnerFactoryImpl::new(&terminal_output);
+    let dotfile_manager_factory = DotfileManagerFactoryImpl::new(&terminal_output);
+    let template_renderer_factory = TemplateRendererFactoryImpl::new(&terminal_output);
+    let directory_creator_factory = DirectoryCreatorFactoryImpl::new(&terminal_output);
+    let environment_manager_factory = EnvironmentManagerFactoryImpl::new(&terminal_output);
+    let line_editor_factory = LineEditorFactoryImpl::new(&terminal_output);
+    let ssh_manager_factory = SshManagerFactoryImpl::new(&terminal_output);
+    let service_manager_factory = ServiceManagerFactoryImpl::new(&terminal_output);
+    let scheduler_factory = SchedulerFactoryImpl::new(&terminal_output);
+    let font_installer_factory = FontInstallerFactoryImpl::new(&file_download_factory, &terminal_output);
+    let version_manager_factory = VersionManagerFactoryImpl::new(&terminal_output);
+    let container_engine_factory = ContainerEngineFactoryImpl::new(&terminal_output);
+    let group_manager_factory = GroupManagerFactoryImpl::new(&terminal_output);
+    let parser_factory = ParserFactory::new(&ParserFactories {
+        package_manager_factory,
+        version_control_system_factory,
+        file_download_factory,
+        file_copy_factory,
+        script_runner_factory,
+        dotfile_manager_factory,
+        template_renderer_factory,
+        directory_creator_factory,
+        environment_manager_factory,
+        line_editor_factory,
+        ssh_manager_factory,
+        service_manager_factory,
+        scheduler_factory,
+        font_installer_factory,
+        version_manager_factory,
+        container_engine_factory,
+        group_manager_factory
+    });
 
     let args = arguments::Arguments::parse();
     let configuration = yaml_file_reader.read_configuration(&args.configuration_file)
@@ -0,0 +1,167 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Get the comment line which marks the start of the managed block
+fn begin_marker(comment: &str, name: &str) -> String {
+    return format!("{} BEGIN upset {}", &comment, &name);
+}
+
+/// Get the comment line which marks the end of the managed block
+fn end_marker(comment: &str, name: &str) -> String {
+    return format!("{} END upset {}", &comment, &name);
+}
+
+/// Place the block between the upset marker comments in the contents
+///
+/// An existing block with the same name is replaced, otherwise the block is
+/// appended to the contents. When no block is given the existing block is
+/// removed together with its markers
+pub fn set_managed_block(contents: &str, comment: &str, name: &str, block: Option<&str>) -> String {
+    let begin = begin_marker(&comment, &name);
+    let end = end_marker(&comment, &name);
+    let lines: Vec<&str> = contents.lines().collect();
+    let mut result: Vec<String> = Vec::new();
+    let mut replaced = false;
+    let mut index = 0;
+
+    while index < lines.len() {
+        let end_index = lines[index..].iter().position(|line| line.trim() == end);
+
+        match end_index {
+            Some(end_index) if lines[index].trim() == begin => {
+                if let Some(block) = block {
+                    result.extend(create_block(&begin, &end, &block));
+                }
+
+                index += end_index + 1;
+                replaced = true;
+            },
+            _ => {
+                result.push(lines[index].to_string());
+                index += 1;
+            }
+        }
+    }
+
+    if !replaced {
+        if let Some(block) = block {
+            result.extend(create_block(&begin, &end, &block));
+        }
+    }
+
+    if result.is_empty() {
+        return String::new();
+    }
+
+    return result.join("\n") + "\n";
+}
+
+/// Place the block between the upset marker comments in the file,
+/// which is created when it doesn't exist yet
+///
+/// Returns whether the file has been changed
+pub fn write_managed_block(path: &Path, comment: &str, name: &str, block: Option<&str>) -> io::Result<bool> {
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound && block.is_some() => String::new(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e)
+    };
+    let updated = set_managed_block(&contents, &comment, &name, block);
+
+    if updated == contents {
+        return Ok(false);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(&parent)?;
+    }
+
+    fs::write(&path, &updated)?;
+    return Ok(true);
+}
+
+fn create_block(begin: &str, end: &str, block: &str) -> Vec<String> {
+    let mut lines = vec!(begin.to_string());
+    lines.extend(block.lines().map(|line| line.to_string()));
+    lines.push(end.to_string());
+
+    return lines;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::managed_block::set_managed_block;
+
+    #[test]
+    fn set_managed_block_appends_the_block_when_it_does_not_exist() {
+        // Arrange
+        let contents = "alias ll='ls -l'";
+
+        // Act
+        let result = set_managed_block(&contents, "#", "environment", Some("export EDITOR=\"nvim\""));
+
+        // Assert
+        assert_eq!(
+            "alias ll='ls -l'\n# BEGIN upset environment\nexport EDITOR=\"nvim\"\n# END upset environment\n",
+            result
+        );
+    }
+
+    #[test]
+    fn set_managed_block_replaces_the_existing_block() {
+        // Arrange
+        let contents = "\
+# BEGIN upset environment
+export EDITOR=\"vim\"
+# END upset environment
+alias ll='ls -l'
+";
+
+        // Act
+        let result = set_managed_block(&contents, "#", "environment", Some("export EDITOR=\"nvim\""));
+
+        // Assert
+        assert_eq!(
+            "# BEGIN upset environment\nexport EDITOR=\"nvim\"\n# END upset environment\nalias ll='ls -l'\n",
+            result
+        );
+    }
+
+    #[test]
+    fn set_managed_block_does_not_change_the_contents_when_the_block_is_up_to_date() {
+        // Arrange
+        let contents = "alias ll='ls -l'\n# BEGIN upset environment\nexport EDITOR=\"nvim\"\n# END upset environment\n";
+
+        // Act
+        let result = set_managed_block(&contents, "#", "environment", Some("export EDITOR=\"nvim\""));
+
+        // Assert
+        assert_eq!(contents, result);
+    }
+
+    #[test]
+    fn set_managed_block_removes_the_block_without_a_block() {
+        // Arrange
+        let contents = "alias ll='ls -l'\n# BEGIN upset environment\nexport EDITOR=\"nvim\"\n# END upset environment\n";
+
+        // Act
+        let result = set_managed_block(&contents, "#", "environment", None);
+
+        // Assert
+        assert_eq!("alias ll='ls -l'\n", result);
+    }
+
+    #[test]
+    fn set_managed_block_keeps_blocks_with_a_different_name() {
+        // Arrange
+        let contents = "# BEGIN upset hosts\n127.0.0.1 upset\n# END upset hosts\n";
+
+        // Act
+        let result = set_managed_block(&contents, "#", "environment", None);
+
+        // Assert
+        assert_eq!(contents, result);
+    }
+}
@@ -1,5 +1,6 @@
 mod package_manager;
 mod winget_package_manager;
+mod vscode_package_manager;
 mod package_manager_factory;
 
 pub use package_manager::MockPackageManager;
@@ -1,6 +1,7 @@
 use std::sync::Arc;
-use crate::commands::WingetCommand;
+use crate::commands::{VscodeCommand, WingetCommand};
 use crate::package_manager::package_manager::PackageManager;
+use crate::package_manager::vscode_package_manager::VscodePackageManager;
 use crate::package_manager::winget_package_manager::WingetPackageManager;
 use crate::terminal::TerminalOutput;
 
@@ -18,6 +19,12 @@ impl PackageManagerFactory for PackageManagerFactoryImpl {
             "winget" => Some(
                 WingetPackageManager::new(&WingetCommand::new(), &source, &self.terminal_output)
             ),
+            "vscode" => match source.to_lowercase().as_str() {
+                editor @ ("code" | "code-insiders" | "codium") => Some(
+                    VscodePackageManager::new(&VscodeCommand::new(&editor), &self.terminal_output)
+                ),
+                _ => None
+            },
             _ => None
         };
     }
@@ -35,6 +42,7 @@ impl PackageManagerFactoryImpl {
 mod tests {
     use std::sync::Arc;
     use crate::package_manager::package_manager_factory::PackageManagerFactoryImpl;
+    use crate::package_manager::vscode_package_manager::VscodePackageManager;
     use crate::package_manager::winget_package_manager::WingetPackageManager;
     use crate::terminal::{MockTerminalOutput, TerminalOutput};
 
@@ -83,4 +91,35 @@ mod tests {
         assert!(result.is_some());
         assert!(result.unwrap().as_any().is::<WingetPackageManager>());
     }
+
+    #[test]
+    fn get_package_manager_returns_vscode_for_vscode_package_manager() {
+        // Arrange
+        let name = "vscode".to_string();
+        let source = "codium".to_string();
+        let terminal_output = MockTerminalOutput::new();
+
+        // Act
+        let sut = PackageManagerFactoryImpl::new(&(Arc::new(terminal_output) as Arc<dyn TerminalOutput>));
+        let result = sut.get_package_manager(&name, &source);
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<VscodePackageManager>());
+    }
+
+    #[test]
+    fn get_package_manager_returns_none_for_unsupported_vscode_editor() {
+        // Arrange
+        let name = "vscode".to_string();
+        let source = "notepad".to_string();
+        let terminal_output = MockTerminalOutput::new();
+
+        // Act
+        let sut = PackageManagerFactoryImpl::new(&(Arc::new(terminal_output) as Arc<dyn TerminalOutput>));
+        let result = sut.get_package_manager(&name, &source);
+
+        // Assert
+        assert!(result.is_none());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,156 @@
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
+use crate::command::Command;
+use crate::package_manager::package_manager::PackageManager;
+use crate::terminal::TerminalOutput;
+
+/// Installs extensions into Visual Studio Code
+pub struct VscodePackageManager {
+    /// The command line interface of the editor
+    vscode_command: Arc<dyn Command>,
+
+    /// Terminal output where the installation status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl PackageManager for VscodePackageManager {
+    fn install(&self, applications: &Vec<String>) {
+        let installed_extensions = self.get_installed_extensions();
+
+        for application in applications {
+            self.install_extension(&application, &installed_extensions);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl VscodePackageManager {
+    pub fn new(vscode_command: &Arc<dyn Command>, terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn PackageManager> {
+        return Arc::new(Self {
+            vscode_command: vscode_command.clone(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    /// Get the identifiers of the installed extensions, which are case-insensitive
+    fn get_installed_extensions(&self) -> HashSet<String> {
+        return self.vscode_command
+            .output(&["--list-extensions".to_string()])
+            .unwrap_or_default()
+            .lines()
+            .map(|extension| extension.trim().to_lowercase())
+            .filter(|extension| !extension.is_empty())
+            .collect();
+    }
+
+    fn install_extension(&self, extension: &String, installed_extensions: &HashSet<String>) {
+        self.terminal_output.loading(&format!("Installing {}", &extension));
+
+        if installed_extensions.contains(&extension.to_lowercase()) {
+            self.terminal_output.finish_with_success(&format!("{} is already installed", &extension));
+            return;
+        }
+
+        let result = self.vscode_command
+            .execute(&[
+                "--install-extension".to_string(),
+                extension.to_string()
+            ]);
+        let is_success = result.is_ok_and(|result| result);
+
+        if is_success {
+            self.terminal_output.finish_with_success(&format!("Successfully installed {}", &extension));
+        } else {
+            self.terminal_output.finish_with_warning(&format!("Unable to install {}", &extension));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::command::{Command, MockCommand};
+    use crate::package_manager::vscode_package_manager::VscodePackageManager;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output_mock;
+
+    #[test]
+    fn install_calls_the_vscode_command_with_the_expected_arguments() {
+        // Arrange
+        let applications = vec!("rust-lang.rust-analyzer".to_string());
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_output()
+            .withf(|args| args == &["--list-extensions".to_string()])
+            .once()
+            .returning(|_| Ok(String::default()));
+        command_mock.expect_execute()
+            .withf(|args| args == &[
+                "--install-extension".to_string(),
+                "rust-lang.rust-analyzer".to_string()
+            ])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = VscodePackageManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.install(&applications);
+    }
+
+    #[test]
+    fn install_skips_the_extensions_which_are_already_installed() {
+        // Arrange
+        let applications = vec!("Rust-Lang.Rust-Analyzer".to_string(), "vscodevim.vim".to_string());
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_output()
+            .returning(|_| Ok("ms-python.python\nrust-lang.rust-analyzer\n".to_string()));
+        command_mock.expect_execute()
+            .withf(|args| args[1] == "vscodevim.vim")
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = VscodePackageManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.install(&applications);
+    }
+
+    #[test]
+    fn install_calls_finish_with_warning_on_the_the_terminal_output_when_the_command_fails() {
+        // Arrange
+        let applications = vec!("rust-lang.rust-analyzer".to_string());
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the mocks
+        command_mock.expect_output().returning(|_| Err(String::default()));
+        command_mock.expect_execute().returning(|_| Ok(false));
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = VscodePackageManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        _ = sut.install(&applications);
+    }
+}
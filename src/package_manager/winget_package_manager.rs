@@ -67,6 +67,7 @@ mod tests {
     use crate::command::{Command, MockCommand};
     use crate::package_manager::winget_package_manager::WingetPackageManager;
     use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output_mock;
 
     #[test]
     fn install_calls_the_winget_command_for_each_application() {
@@ -208,15 +209,4 @@ mod tests {
         let sut = WingetPackageManager::new(&(Arc::new(command_mock) as Arc<dyn Command>), &source, &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
         _ = sut.install(&applications);
     }
-
-    fn setup_terminal_output_mock() -> MockTerminalOutput {
-        let mut terminal_output_mock = MockTerminalOutput::new();
-
-        // Setup the expectation
-        terminal_output_mock.expect_loading().returning(|_| {});
-        terminal_output_mock.expect_finish_with_success().returning(|_| {});
-        terminal_output_mock.expect_finish_with_warning().returning(|_| {});
-
-        return terminal_output_mock;
-    }
 }
\ No newline at end of file
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+use crate::configuration::ScriptConfiguration;
+use crate::container::{ContainerEngine, ContainerEngineFactory, MockContainerEngine};
+use crate::directory::{DirectoryCreator, DirectoryCreatorFactory, MockDirectoryCreator};
+use crate::dotfiles::{DotfileManager, DotfileManagerFactory, MockDotfileManager};
+use crate::environment::{EnvironmentManager, EnvironmentManagerFactory, MockEnvironmentManager};
+use crate::file_copy::{FileCopy, FileCopyFactory, MockFileCopy};
+use crate::file_download::{FileDownload, FileDownloadFactory, MockFileDownload};
+use crate::font::{FontInstaller, FontInstallerFactory, MockFontInstaller};
+use crate::group::{GroupManager, GroupManagerFactory, MockGroupManager};
+use crate::line_in_file::{LineEditor, LineEditorFactory, MockLineEditor};
+use crate::package_manager::{MockPackageManager, PackageManager, PackageManagerFactory};
+use crate::parser::parser_factories::ParserFactories;
+use crate::runtime::{MockVersionManager, VersionManager, VersionManagerFactory};
+use crate::schedule::{MockScheduler, Scheduler, SchedulerFactory};
+use crate::script::{MockScriptRunner, ScriptRunner, ScriptRunnerFactory};
+use crate::service::{MockServiceManager, ServiceManager, ServiceManagerFactory};
+use crate::ssh::{MockSshManager, SshManager, SshManagerFactory};
+use crate::template::{MockTemplateRenderer, TemplateRenderer, TemplateRendererFactory};
+use crate::version_control::{MockVersionControlSystem, VersionControlSystem, VersionControlSystemFactory};
+
+pub struct MockPackageManagerFactory { pub package_manager: Arc<dyn PackageManager> }
+pub struct MockVersionControlSystemFactory { pub version_control: Arc<dyn VersionControlSystem> }
+pub struct MockFileDownloadFactory { pub file_download: Arc<dyn FileDownload> }
+pub struct MockFileCopyFactory { pub file_copy: Arc<dyn FileCopy> }
+pub struct MockScriptRunnerFactory { pub script_runner: Arc<dyn ScriptRunner> }
+pub struct MockDotfileManagerFactory { pub dotfile_manager: Arc<dyn DotfileManager> }
+pub struct MockTemplateRendererFactory { pub template_renderer: Arc<dyn TemplateRenderer> }
+pub struct MockDirectoryCreatorFactory { pub directory_creator: Arc<dyn DirectoryCreator> }
+pub struct MockEnvironmentManagerFactory { pub environment_manager: Arc<dyn EnvironmentManager> }
+pub struct MockLineEditorFactory { pub line_editor: Arc<dyn LineEditor> }
+pub struct MockSshManagerFactory { pub ssh_manager: Arc<dyn SshManager> }
+pub struct MockServiceManagerFactory { pub service_manager: Arc<dyn ServiceManager> }
+pub struct MockSchedulerFactory { pub scheduler: Arc<dyn Scheduler> }
+pub struct MockFontInstallerFactory { pub font_installer: Arc<dyn FontInstaller> }
+pub struct MockVersionManagerFactory { pub version_manager: Arc<dyn VersionManager> }
+pub struct MockContainerEngineFactory { pub container_engine: Arc<dyn ContainerEngine> }
+pub struct MockGroupManagerFactory { pub group_manager: Arc<dyn GroupManager> }
+
+/// Create factories which return mocks without any expectations, so each test only has
+/// to replace the factory it exercises and the test fails when any other part is used
+pub fn create_parser_factories() -> ParserFactories {
+    return ParserFactories {
+        package_manager_factory: Arc::new(MockPackageManagerFactory { package_manager: Arc::new(MockPackageManager::new()) }),
+        version_control_system_factory: Arc::new(MockVersionControlSystemFactory { version_control: Arc::new(MockVersionControlSystem::new()) }),
+        file_download_factory: Arc::new(MockFileDownloadFactory { file_download: Arc::new(MockFileDownload::new()) }),
+        file_copy_factory: Arc::new(MockFileCopyFactory { file_copy: Arc::new(MockFileCopy::new()) }),
+        script_runner_factory: Arc::new(MockScriptRunnerFactory { script_runner: Arc::new(MockScriptRunner::new()) }),
+        dotfile_manager_factory: Arc::new(MockDotfileManagerFactory { dotfile_manager: Arc::new(MockDotfileManager::new()) }),
+        template_renderer_factory: Arc::new(MockTemplateRendererFactory { template_renderer: Arc::new(MockTemplateRenderer::new()) }),
+        directory_creator_factory: Arc::new(MockDirectoryCreatorFactory { directory_creator: Arc::new(MockDirectoryCreator::new()) }),
+        environment_manager_factory: Arc::new(MockEnvironmentManagerFactory { environment_manager: Arc::new(MockEnvironmentManager::new()) }),
+        line_editor_factory: Arc::new(MockLineEditorFactory { line_editor: Arc::new(MockLineEditor::new()) }),
+        ssh_manager_factory: Arc::new(MockSshManagerFactory { ssh_manager: Arc::new(MockSshManager::new()) }),
+        service_manager_factory: Arc::new(MockServiceManagerFactory { service_manager: Arc::new(MockServiceManager::new()) }),
+        scheduler_factory: Arc::new(MockSchedulerFactory { scheduler: Arc::new(MockScheduler::new()) }),
+        font_installer_factory: Arc::new(MockFontInstallerFactory { font_installer: Arc::new(MockFontInstaller::new()) }),
+        version_manager_factory: Arc::new(MockVersionManagerFactory { version_manager: Arc::new(MockVersionManager::new()) }),
+        container_engine_factory: Arc::new(MockContainerEngineFactory { container_engine: Arc::new(MockContainerEngine::new()) }),
+        group_manager_factory: Arc::new(MockGroupManagerFactory { group_manager: Arc::new(MockGroupManager::new()) })
+    };
+}
+
+impl PackageManagerFactory for MockPackageManagerFactory {
+    fn get_package_manager(&self, _: &String, _: &String) -> Option<Arc<dyn PackageManager>> {
+        Some(self.package_manager.clone())
+    }
+}
+
+impl VersionControlSystemFactory for MockVersionControlSystemFactory {
+    fn get_version_control_system(&self, _: &str, _: &str) -> Option<Arc<dyn VersionControlSystem>> {
+        Some(self.version_control.clone())
+    }
+}
+
+impl FileDownloadFactory for MockFileDownloadFactory {
+    fn get_file_downloader(&self, _: &str, _: &str) -> Option<Arc<dyn FileDownload>> {
+        Some(self.file_download.clone())
+    }
+}
+
+impl FileCopyFactory for MockFileCopyFactory {
+    fn get_file_copy(&self) -> Arc<dyn FileCopy> {
+        self.file_copy.clone()
+    }
+}
+
+impl ScriptRunnerFactory for MockScriptRunnerFactory {
+    fn get_script_runner(&self, _: &ScriptConfiguration) -> Option<Arc<dyn ScriptRunner>> {
+        Some(self.script_runner.clone())
+    }
+}
+
+impl DotfileManagerFactory for MockDotfileManagerFactory {
+    fn get_dotfile_manager(&self) -> Arc<dyn DotfileManager> {
+        self.dotfile_manager.clone()
+    }
+}
+
+impl TemplateRendererFactory for MockTemplateRendererFactory {
+    fn get_template_renderer(&self) -> Arc<dyn TemplateRenderer> {
+        self.template_renderer.clone()
+    }
+}
+
+impl DirectoryCreatorFactory for MockDirectoryCreatorFactory {
+    fn get_directory_creator(&self) -> Arc<dyn DirectoryCreator> {
+        self.directory_creator.clone()
+    }
+}
+
+impl EnvironmentManagerFactory for MockEnvironmentManagerFactory {
+    fn get_environment_manager(&self) -> Option<Arc<dyn EnvironmentManager>> {
+        Some(self.environment_manager.clone())
+    }
+}
+
+impl LineEditorFactory for MockLineEditorFactory {
+    fn get_line_editor(&self) -> Arc<dyn LineEditor> {
+        self.line_editor.clone()
+    }
+}
+
+impl SshManagerFactory for MockSshManagerFactory {
+    fn get_ssh_manager(&self) -> Option<Arc<dyn SshManager>> {
+        Some(self.ssh_manager.clone())
+    }
+}
+
+impl ServiceManagerFactory for MockServiceManagerFactory {
+    fn get_service_manager(&self) -> Option<Arc<dyn ServiceManager>> {
+        Some(self.service_manager.clone())
+    }
+}
+
+impl SchedulerFactory for MockSchedulerFactory {
+    fn get_scheduler(&self) -> Option<Arc<dyn Scheduler>> {
+        Some(self.scheduler.clone())
+    }
+}
+
+impl FontInstallerFactory for MockFontInstallerFactory {
+    fn get_font_installer(&self) -> Option<Arc<dyn FontInstaller>> {
+        Some(self.font_installer.clone())
+    }
+}
+
+impl VersionManagerFactory for MockVersionManagerFactory {
+    fn get_version_manager(&self, _: &String) -> Option<Arc<dyn VersionManager>> {
+        Some(self.version_manager.clone())
+    }
+}
+
+impl ContainerEngineFactory for MockContainerEngineFactory {
+    fn get_container_engine(&self, _: &String) -> Option<Arc<dyn ContainerEngine>> {
+        Some(self.container_engine.clone())
+    }
+}
+
+impl GroupManagerFactory for MockGroupManagerFactory {
+    fn get_group_manager(&self) -> Option<Arc<dyn GroupManager>> {
+        Some(self.group_manager.clone())
+    }
+}
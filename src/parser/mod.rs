@@ -1,5 +1,9 @@
 mod parser_factory;
+mod parser_factories;
 mod version_100_parser;
 mod parser;
+#[cfg(test)]
+mod mock_parser_factories;
 
-pub use parser_factory::ParserFactory;
\ No newline at end of file
+pub use parser_factory::ParserFactory;
+pub use parser_factories::ParserFactories;
\ No newline at end of file
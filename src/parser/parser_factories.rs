@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use crate::container::ContainerEngineFactory;
+use crate::directory::DirectoryCreatorFactory;
+use crate::dotfiles::DotfileManagerFactory;
+use crate::environment::EnvironmentManagerFactory;
+use crate::file_copy::FileCopyFactory;
+use crate::file_download::FileDownloadFactory;
+use crate::font::FontInstallerFactory;
+use crate::group::GroupManagerFactory;
+use crate::line_in_file::LineEditorFactory;
+use crate::package_manager::PackageManagerFactory;
+use crate::runtime::VersionManagerFactory;
+use crate::schedule::SchedulerFactory;
+use crate::script::ScriptRunnerFactory;
+use crate::service::ServiceManagerFactory;
+use crate::ssh::SshManagerFactory;
+use crate::template::TemplateRendererFactory;
+use crate::version_control::VersionControlSystemFactory;
+
+/// The factories a parser uses to get the implementation of each part of the configuration
+#[derive(Clone)]
+pub struct ParserFactories {
+    pub package_manager_factory: Arc<dyn PackageManagerFactory>,
+    pub version_control_system_factory: Arc<dyn VersionControlSystemFactory>,
+    pub file_download_factory: Arc<dyn FileDownloadFactory>,
+    pub file_copy_factory: Arc<dyn FileCopyFactory>,
+    pub script_runner_factory: Arc<dyn ScriptRunnerFactory>,
+    pub dotfile_manager_factory: Arc<dyn DotfileManagerFactory>,
+    pub template_renderer_factory: Arc<dyn TemplateRendererFactory>,
+    pub directory_creator_factory: Arc<dyn DirectoryCreatorFactory>,
+    pub environment_manager_factory: Arc<dyn EnvironmentManagerFactory>,
+    pub line_editor_factory: Arc<dyn LineEditorFactory>,
+    pub ssh_manager_factory: Arc<dyn SshManagerFactory>,
+    pub service_manager_factory: Arc<dyn ServiceManagerFactory>,
+    pub scheduler_factory: Arc<dyn SchedulerFactory>,
+    pub font_installer_factory: Arc<dyn FontInstallerFactory>,
+    pub version_manager_factory: Arc<dyn VersionManagerFactory>,
+    pub container_engine_factory: Arc<dyn ContainerEngineFactory>,
+    pub group_manager_factory: Arc<dyn GroupManagerFactory>
+}
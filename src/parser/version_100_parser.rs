@@ -1,24 +1,36 @@
 use std::any::Any;
 use std::sync::Arc;
-use crate::configuration::{Configuration, DownloadConfiguration, PackageConfiguration, VersionControlConfiguration};
-use crate::file_download::FileDownloadFactory;
-use crate::package_manager::PackageManagerFactory;
+use crate::configuration::{Configuration, ContainerImagesConfiguration, DirectoryConfiguration, DotfilesConfiguration, DownloadConfiguration, DownloadFileConfiguration, EnvironmentConfiguration, FileCopyConfiguration, GroupConfiguration, LineInFileConfiguration, PackageConfiguration, RuntimeConfiguration, ScheduleConfiguration, ScriptConfiguration, ServiceConfiguration, SshConfiguration, TemplateConfiguration, VersionControlConfiguration};
 use crate::parser::parser::Parser;
-use crate::version_control::VersionControlSystemFactory;
+use crate::parser::parser_factories::ParserFactories;
 
 /// Parser for version 1.0.0 of the specification
 pub struct Version100Parser {
-    package_manager_factory: Arc<dyn PackageManagerFactory>,
-    version_control_system_factory: Arc<dyn VersionControlSystemFactory>,
-    file_download_factory: Arc<dyn FileDownloadFactory>
+    factories: ParserFactories
 }
 
 impl Parser for Version100Parser {
     fn parse(&self, configuration: &Configuration) {
+        if let Some(groups) = &configuration.groups {
+            self.parse_groups(groups);
+        }
+
+        if let Some(directories) = &configuration.directories {
+            self.parse_directories(directories);
+        }
+
+        if let Some(ssh) = &configuration.ssh {
+            self.parse_ssh(ssh);
+        }
+
         if let Some(packages) = &configuration.packages {
             self.parse_packages(packages);
         }
 
+        if let Some(runtimes) = &configuration.runtimes {
+            self.parse_runtimes(runtimes);
+        }
+
         if let Some(version_control_systems) = &configuration.version_control {
             self.parse_version_control(version_control_systems);
         }
@@ -26,6 +38,46 @@ impl Parser for Version100Parser {
         if let Some(downloads) = &configuration.downloads {
             self.parse_download_files(downloads);
         }
+
+        if let Some(files) = &configuration.files {
+            self.parse_copy_files(files);
+        }
+
+        if let Some(fonts) = &configuration.fonts {
+            self.parse_fonts(fonts);
+        }
+
+        if let Some(dotfiles) = &configuration.dotfiles {
+            self.parse_dotfiles(dotfiles);
+        }
+
+        if let Some(templates) = &configuration.templates {
+            self.parse_templates(templates);
+        }
+
+        if let Some(lines) = &configuration.line_in_file {
+            self.parse_line_in_file(lines);
+        }
+
+        if let Some(environment) = &configuration.environment {
+            self.parse_environment(environment);
+        }
+
+        if let Some(services) = &configuration.services {
+            self.parse_services(services);
+        }
+
+        if let Some(container_images) = &configuration.container_images {
+            self.parse_container_images(container_images);
+        }
+
+        if let Some(schedules) = &configuration.schedules {
+            self.parse_schedules(schedules);
+        }
+
+        if let Some(scripts) = &configuration.scripts {
+            self.parse_scripts(scripts);
+        }
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -34,21 +86,31 @@ impl Parser for Version100Parser {
 }
 
 impl Version100Parser {
-    pub fn new(
-        package_manager_factory: &Arc<dyn PackageManagerFactory>,
-        version_control_system_factory: &Arc<dyn VersionControlSystemFactory>,
-        file_download_factory: &Arc<dyn FileDownloadFactory>
-    ) -> Arc<Self> {
+    pub fn new(factories: &ParserFactories) -> Arc<Self> {
         return Arc::new(Self {
-            package_manager_factory: package_manager_factory.clone(),
-            version_control_system_factory: version_control_system_factory.clone(),
-            file_download_factory: file_download_factory.clone()
+            factories: factories.clone()
         });
     }
 
+    fn parse_groups(&self, groups: &Vec<GroupConfiguration>) {
+        if let Some(group_manager) = self.factories.group_manager_factory.get_group_manager() {
+            group_manager.configure(&groups);
+        }
+    }
+
+    fn parse_directories(&self, directories: &Vec<DirectoryConfiguration>) {
+        self.factories.directory_creator_factory.get_directory_creator().create(&directories);
+    }
+
+    fn parse_ssh(&self, ssh: &SshConfiguration) {
+        if let Some(ssh_manager) = self.factories.ssh_manager_factory.get_ssh_manager() {
+            ssh_manager.configure(&ssh);
+        }
+    }
+
     fn parse_packages(&self, applications_configuration: &Vec<PackageConfiguration>) {
         for application_config in applications_configuration {
-            let package_manager = &self.package_manager_factory.get_package_manager(
+            let package_manager = &self.factories.package_manager_factory.get_package_manager(
                 &application_config.package_manager,
                 &application_config.source
             );
@@ -59,9 +121,17 @@ impl Version100Parser {
         }
     }
 
+    fn parse_runtimes(&self, runtimes: &Vec<RuntimeConfiguration>) {
+        for runtime in runtimes {
+            if let Some(version_manager) = self.factories.version_manager_factory.get_version_manager(&runtime.version_manager) {
+                version_manager.install(&runtime);
+            }
+        }
+    }
+
     fn parse_version_control(&self, version_control_configuration: &Vec<VersionControlConfiguration>) {
         for version_control_item_config in version_control_configuration {
-            let version_control_system = &self.version_control_system_factory.get_version_control_system(
+            let version_control_system = &self.factories.version_control_system_factory.get_version_control_system(
                 &version_control_item_config.vcs,
                 &version_control_item_config.destination_folder
             );
@@ -74,7 +144,7 @@ impl Version100Parser {
 
     fn parse_download_files(&self, remote_sources: &Vec<DownloadConfiguration>) {
         for remote_source in remote_sources {
-            let download_manager = &self.file_download_factory.get_file_downloader(
+            let download_manager = &self.factories.file_download_factory.get_file_downloader(
                 &remote_source.download_manager,
                 &remote_source.destination_folder
             );
@@ -84,28 +154,106 @@ impl Version100Parser {
             }
         }
     }
+
+    fn parse_copy_files(&self, files: &Vec<FileCopyConfiguration>) {
+        self.factories.file_copy_factory.get_file_copy().copy(&files);
+    }
+
+    fn parse_fonts(&self, fonts: &Vec<DownloadFileConfiguration>) {
+        if let Some(font_installer) = self.factories.font_installer_factory.get_font_installer() {
+            font_installer.install(&fonts);
+        }
+    }
+
+    fn parse_dotfiles(&self, dotfiles_configuration: &Vec<DotfilesConfiguration>) {
+        let dotfile_manager = self.factories.dotfile_manager_factory.get_dotfile_manager();
+
+        for dotfiles in dotfiles_configuration {
+            dotfile_manager.link(&dotfiles);
+        }
+    }
+
+    fn parse_templates(&self, templates: &Vec<TemplateConfiguration>) {
+        self.factories.template_renderer_factory.get_template_renderer().render(&templates);
+    }
+
+    fn parse_line_in_file(&self, lines: &Vec<LineInFileConfiguration>) {
+        self.factories.line_editor_factory.get_line_editor().edit(&lines);
+    }
+
+    fn parse_environment(&self, environment: &EnvironmentConfiguration) {
+        if let Some(environment_manager) = self.factories.environment_manager_factory.get_environment_manager() {
+            environment_manager.set(&environment);
+        }
+    }
+
+    fn parse_services(&self, services: &Vec<ServiceConfiguration>) {
+        if let Some(service_manager) = self.factories.service_manager_factory.get_service_manager() {
+            service_manager.configure(&services);
+        }
+    }
+
+    fn parse_container_images(&self, container_images: &Vec<ContainerImagesConfiguration>) {
+        for container_images_config in container_images {
+            let container_engine = &self.factories.container_engine_factory.get_container_engine(
+                &container_images_config.container_engine
+            );
+
+            if let Some(container_engine) = container_engine {
+                container_engine.pull(&container_images_config.images);
+            }
+        }
+    }
+
+    fn parse_schedules(&self, schedules: &Vec<ScheduleConfiguration>) {
+        if let Some(scheduler) = self.factories.scheduler_factory.get_scheduler() {
+            scheduler.schedule(&schedules);
+        }
+    }
+
+    fn parse_scripts(&self, scripts: &Vec<ScriptConfiguration>) {
+        for script in scripts {
+            let script_runner = &self.factories.script_runner_factory.get_script_runner(&script);
+
+            if let Some(script_runner) = script_runner {
+                script_runner.run(&script);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
     use std::sync::Arc;
-    use crate::configuration::{Configuration, DownloadConfiguration, PackageConfiguration, VersionControlConfiguration};
-    use crate::file_download::{FileDownload, FileDownloadFactory, MockFileDownload};
-    use crate::package_manager::{MockPackageManager, PackageManager, PackageManagerFactory};
+    use mockall::Sequence;
+    use crate::configuration::{Configuration, ContainerImageConfiguration, ContainerImagesConfiguration, DirectoryConfiguration, DotfileLinkConfiguration, DotfilesConfiguration, DownloadConfiguration, DownloadFileConfiguration, EnvironmentConfiguration, FileCopyConfiguration, GroupConfiguration, KnownHostConfiguration, LineInFileConfiguration, PackageConfiguration, RuntimeConfiguration, ScheduleConfiguration, ScriptConfiguration, ServiceConfiguration, SshConfiguration, SshKeyConfiguration, TemplateConfiguration, VersionControlConfiguration};
+    use crate::container::MockContainerEngine;
+    use crate::directory::MockDirectoryCreator;
+    use crate::dotfiles::MockDotfileManager;
+    use crate::environment::MockEnvironmentManager;
+    use crate::file_copy::MockFileCopy;
+    use crate::file_download::MockFileDownload;
+    use crate::font::MockFontInstaller;
+    use crate::group::MockGroupManager;
+    use crate::line_in_file::MockLineEditor;
+    use crate::package_manager::MockPackageManager;
+    use crate::parser::mock_parser_factories::{create_parser_factories, MockContainerEngineFactory, MockDirectoryCreatorFactory, MockDotfileManagerFactory, MockEnvironmentManagerFactory, MockFileCopyFactory, MockFileDownloadFactory, MockFontInstallerFactory, MockGroupManagerFactory, MockLineEditorFactory, MockPackageManagerFactory, MockSchedulerFactory, MockScriptRunnerFactory, MockServiceManagerFactory, MockSshManagerFactory, MockTemplateRendererFactory, MockVersionControlSystemFactory, MockVersionManagerFactory};
     use crate::parser::parser::Parser;
+    use crate::parser::parser_factories::ParserFactories;
     use crate::parser::version_100_parser::Version100Parser;
-    use crate::version_control::{MockVersionControlSystem, VersionControlSystem, VersionControlSystemFactory};
-
-    struct MockPackageManagerFactory { package_manager: Arc<dyn PackageManager> }
-    struct MockVersionControlSystemFactory { version_control: Arc<dyn VersionControlSystem> }
-    struct MockFileDownloadFactory { file_download: Arc<dyn FileDownload> }
+    use crate::runtime::MockVersionManager;
+    use crate::schedule::MockScheduler;
+    use crate::script::MockScriptRunner;
+    use crate::service::MockServiceManager;
+    use crate::ssh::MockSshManager;
+    use crate::template::MockTemplateRenderer;
+    use crate::version_control::MockVersionControlSystem;
 
     #[test]
     fn parse_should_parse_the_packages_when_they_are_available() {
         // Arrange
         let mut mock_package_manager = MockPackageManager::new();
-        let mock_version_control_system = MockVersionControlSystem::new();
-        let mock_file_download = MockFileDownload::new();
 
         // Setup expectations
         mock_package_manager.expect_install()
@@ -113,9 +261,10 @@ mod tests {
             .withf(|args| args.eq(&vec!("upset".to_string(), "ItDepends".to_string())))
             .returning(|_| {});
 
-        let mock_package_manager_factory = MockPackageManagerFactory { package_manager: Arc::new(mock_package_manager) };
-        let mock_version_control_system_factory = MockVersionControlSystemFactory { version_control: Arc::new(mock_version_control_system) };
-        let mock_file_download_factory = MockFileDownloadFactory { file_download: Arc::new(mock_file_download) };
+        let factories = ParserFactories {
+            package_manager_factory: Arc::new(MockPackageManagerFactory { package_manager: Arc::new(mock_package_manager) }),
+            ..create_parser_factories()
+        };
 
         let config = Configuration {
             packages: Some(vec!(
@@ -126,24 +275,19 @@ mod tests {
                 }
             )),
             version_control: None,
-            downloads: None
+            downloads: None,
+            ..Default::default()
         };
 
         // Act
-        let sut = Version100Parser::new(
-            &(Arc::new(mock_package_manager_factory) as Arc<dyn PackageManagerFactory>),
-            &(Arc::new(mock_version_control_system_factory) as Arc<dyn VersionControlSystemFactory>),
-            &(Arc::new(mock_file_download_factory) as Arc<dyn FileDownloadFactory>)
-        );
+        let sut = Version100Parser::new(&factories);
         _ = sut.parse(&config);
     }
 
     #[test]
     fn parse_should_parse_the_version_control_systems_when_they_are_available() {
         // Arrange
-        let mock_package_manager = MockPackageManager::new();
         let mut mock_version_control_system = MockVersionControlSystem::new();
-        let mock_file_download = MockFileDownload::new();
 
         // Setup expectations
         mock_version_control_system.expect_download()
@@ -154,9 +298,10 @@ mod tests {
             )))
             .returning(|_| {});
 
-        let mock_package_manager_factory = MockPackageManagerFactory { package_manager: Arc::new(mock_package_manager) };
-        let mock_version_control_system_factory = MockVersionControlSystemFactory { version_control: Arc::new(mock_version_control_system) };
-        let mock_file_download_factory = MockFileDownloadFactory { file_download: Arc::new(mock_file_download) };
+        let factories = ParserFactories {
+            version_control_system_factory: Arc::new(MockVersionControlSystemFactory { version_control: Arc::new(mock_version_control_system) }),
+            ..create_parser_factories()
+        };
 
         let config = Configuration {
             packages: None,
@@ -170,37 +315,33 @@ mod tests {
                     ),
                 }
             )),
-            downloads: None
+            downloads: None,
+            ..Default::default()
         };
 
         // Act
-        let sut = Version100Parser::new(
-            &(Arc::new(mock_package_manager_factory) as Arc<dyn PackageManagerFactory>),
-            &(Arc::new(mock_version_control_system_factory) as Arc<dyn VersionControlSystemFactory>),
-            &(Arc::new(mock_file_download_factory) as Arc<dyn FileDownloadFactory>)
-        );
+        let sut = Version100Parser::new(&factories);
         _ = sut.parse(&config);
     }
 
     #[test]
     fn parse_should_parse_the_file_downloads_when_they_are_available() {
         // Arrange
-        let mock_package_manager = MockPackageManager::new();
-        let mock_version_control_system = MockVersionControlSystem::new();
         let mut mock_file_download = MockFileDownload::new();
 
         // Setup expectations
         mock_file_download.expect_download()
             .once()
             .withf(|args| args.eq(&vec!(
-                "https://bartkessels.net/download/upset".to_string(),
-                "https://bartkessels.net/download/it-depends".to_string()
+                DownloadFileConfiguration::from("https://bartkessels.net/download/upset".to_string()),
+                DownloadFileConfiguration::from("https://bartkessels.net/download/it-depends".to_string())
             )))
             .returning(|_| {});
 
-        let mock_package_manager_factory = MockPackageManagerFactory { package_manager: Arc::new(mock_package_manager) };
-        let mock_version_control_system_factory = MockVersionControlSystemFactory { version_control: Arc::new(mock_version_control_system) };
-        let mock_file_download_factory = MockFileDownloadFactory { file_download: Arc::new(mock_file_download) };
+        let factories = ParserFactories {
+            file_download_factory: Arc::new(MockFileDownloadFactory { file_download: Arc::new(mock_file_download) }),
+            ..create_parser_factories()
+        };
 
         let config = Configuration {
             packages: None,
@@ -210,75 +351,519 @@ mod tests {
                     download_manager: "wget".to_string(),
                     destination_folder: "~/Downloads".to_string(),
                     files: vec!(
-                        "https://bartkessels.net/download/upset".to_string(),
-                        "https://bartkessels.net/download/it-depends".to_string()
+                        DownloadFileConfiguration::from("https://bartkessels.net/download/upset".to_string()),
+                        DownloadFileConfiguration::from("https://bartkessels.net/download/it-depends".to_string())
                     ),
                 }
-            ))
+            )),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn parse_should_parse_the_files_to_copy_when_they_are_available() {
+        // Arrange
+        let mut mock_file_copy = MockFileCopy::new();
+        let file = FileCopyConfiguration {
+            source: "~/Git-projects/dotfiles/gitconfig".to_string(),
+            destination: "~/.gitconfig".to_string(),
+            ..Default::default()
+        };
+        let expected_files = vec!(file.clone());
+
+        // Setup expectations
+        mock_file_copy.expect_copy()
+            .once()
+            .withf(move |args| args.eq(&expected_files))
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            file_copy_factory: Arc::new(MockFileCopyFactory { file_copy: Arc::new(mock_file_copy) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            files: Some(vec!(file)),
+            ..Default::default()
         };
 
         // Act
-        let sut = Version100Parser::new(
-            &(Arc::new(mock_package_manager_factory) as Arc<dyn PackageManagerFactory>),
-            &(Arc::new(mock_version_control_system_factory) as Arc<dyn VersionControlSystemFactory>),
-            &(Arc::new(mock_file_download_factory) as Arc<dyn FileDownloadFactory>)
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn parse_should_run_the_scripts_when_they_are_available() {
+        // Arrange
+        let mut mock_script_runner = MockScriptRunner::new();
+        let scripts = vec!(
+            ScriptConfiguration {
+                run: Some("rustup default stable".to_string()),
+                ..Default::default()
+            },
+            ScriptConfiguration {
+                path: Some("~/install.py".to_string()),
+                interpreter: Some("python".to_string()),
+                ..Default::default()
+            }
         );
+
+        // Setup expectations
+        mock_script_runner.expect_run()
+            .times(scripts.len())
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            script_runner_factory: Arc::new(MockScriptRunnerFactory { script_runner: Arc::new(mock_script_runner) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            scripts: Some(scripts),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
         _ = sut.parse(&config);
     }
 
     #[test]
-    fn should_not_parse_anything_when_the_configuration_is_empty() {
+    fn parse_should_link_the_dotfiles_when_they_are_available() {
         // Arrange
-        let mut mock_package_manager = MockPackageManager::new();
-        let mut mock_version_control_system = MockVersionControlSystem::new();
-        let mut mock_file_download = MockFileDownload::new();
+        let mut mock_dotfile_manager = MockDotfileManager::new();
+        let dotfiles = DotfilesConfiguration {
+            source_folder: "~/Git-projects/dotfiles".to_string(),
+            links: vec!(DotfileLinkConfiguration::from(".gitconfig".to_string())),
+            ..Default::default()
+        };
+        let expected_dotfiles = dotfiles.clone();
 
         // Setup expectations
-        mock_package_manager.expect_install()
-            .never()
+        mock_dotfile_manager.expect_link()
+            .once()
+            .withf(move |args| args.eq(&expected_dotfiles))
             .returning(|_| {});
-        mock_version_control_system.expect_download()
-            .never()
+
+        let factories = ParserFactories {
+            dotfile_manager_factory: Arc::new(MockDotfileManagerFactory { dotfile_manager: Arc::new(mock_dotfile_manager) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            dotfiles: Some(vec!(dotfiles)),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn parse_should_render_the_templates_when_they_are_available() {
+        // Arrange
+        let mut mock_template_renderer = MockTemplateRenderer::new();
+        let template = TemplateConfiguration {
+            source: "~/Git-projects/dotfiles/gitconfig".to_string(),
+            destination: "~/.gitconfig".to_string(),
+            ..Default::default()
+        };
+        let expected_templates = vec!(template.clone());
+
+        // Setup expectations
+        mock_template_renderer.expect_render()
+            .once()
+            .withf(move |args| args.eq(&expected_templates))
             .returning(|_| {});
-        mock_file_download.expect_download()
-            .never()
+
+        let factories = ParserFactories {
+            template_renderer_factory: Arc::new(MockTemplateRendererFactory { template_renderer: Arc::new(mock_template_renderer) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            templates: Some(vec!(template)),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn parse_should_schedule_the_commands_when_they_are_available() {
+        // Arrange
+        let mut mock_scheduler = MockScheduler::new();
+        let schedule = ScheduleConfiguration {
+            name: "backup".to_string(),
+            schedule: "@daily".to_string(),
+            command: "~/bin/backup.sh".to_string()
+        };
+        let expected_schedules = vec!(schedule.clone());
+
+        // Setup expectations
+        mock_scheduler.expect_schedule()
+            .once()
+            .withf(move |args| args.eq(&expected_schedules))
             .returning(|_| {});
 
-        let mock_package_manager_factory = MockPackageManagerFactory { package_manager: Arc::new(mock_package_manager) };
-        let mock_version_control_system_factory = MockVersionControlSystemFactory { version_control: Arc::new(mock_version_control_system) };
-        let mock_file_download_factory = MockFileDownloadFactory { file_download: Arc::new(mock_file_download) };
+        let factories = ParserFactories {
+            scheduler_factory: Arc::new(MockSchedulerFactory { scheduler: Arc::new(mock_scheduler) }),
+            ..create_parser_factories()
+        };
 
         let config = Configuration {
-            packages: None,
-            version_control: None,
-            downloads: None
+            schedules: Some(vec!(schedule)),
+            ..Default::default()
         };
 
         // Act
-        let sut = Version100Parser::new(
-            &(Arc::new(mock_package_manager_factory) as Arc<dyn PackageManagerFactory>),
-            &(Arc::new(mock_version_control_system_factory) as Arc<dyn VersionControlSystemFactory>),
-            &(Arc::new(mock_file_download_factory) as Arc<dyn FileDownloadFactory>)
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn parse_should_install_the_fonts_when_they_are_available() {
+        // Arrange
+        let mut mock_font_installer = MockFontInstaller::new();
+        let font = DownloadFileConfiguration::from(
+            "https://github.com/ryanoasis/nerd-fonts/releases/latest/download/FiraCode.zip".to_string()
         );
+        let expected_fonts = vec!(font.clone());
+
+        // Setup expectations
+        mock_font_installer.expect_install()
+            .once()
+            .withf(move |args| args.eq(&expected_fonts))
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            font_installer_factory: Arc::new(MockFontInstallerFactory { font_installer: Arc::new(mock_font_installer) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            fonts: Some(vec!(font)),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
         _ = sut.parse(&config);
     }
 
-    impl PackageManagerFactory for MockPackageManagerFactory {
-        fn get_package_manager(&self, _: &String, _: &String) -> Option<Arc<dyn PackageManager>> {
-            Some(self.package_manager.clone())
-        }
+    #[test]
+    fn parse_should_configure_the_groups_when_they_are_available() {
+        // Arrange
+        let mut mock_group_manager = MockGroupManager::new();
+        let group = GroupConfiguration {
+            name: "docker".to_string(),
+            members: vec!("bart".to_string())
+        };
+        let expected_groups = vec!(group.clone());
+
+        // Setup expectations
+        mock_group_manager.expect_configure()
+            .once()
+            .withf(move |args| args.eq(&expected_groups))
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            group_manager_factory: Arc::new(MockGroupManagerFactory { group_manager: Arc::new(mock_group_manager) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            groups: Some(vec!(group)),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
     }
 
-    impl VersionControlSystemFactory for MockVersionControlSystemFactory {
-        fn get_version_control_system(&self, _: &str, _: &str) -> Option<Arc<dyn VersionControlSystem>> {
-            Some(self.version_control.clone())
-        }
+    #[test]
+    fn parse_should_pull_the_container_images_when_they_are_available() {
+        // Arrange
+        let mut mock_container_engine = MockContainerEngine::new();
+        let image = ContainerImageConfiguration::from("postgres:16".to_string());
+        let expected_images = vec!(image.clone());
+
+        // Setup expectations
+        mock_container_engine.expect_pull()
+            .once()
+            .withf(move |args| args.eq(&expected_images))
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            container_engine_factory: Arc::new(MockContainerEngineFactory { container_engine: Arc::new(mock_container_engine) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            container_images: Some(vec!(
+                ContainerImagesConfiguration {
+                    container_engine: "docker".to_string(),
+                    images: vec!(image)
+                }
+            )),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
     }
 
-    impl FileDownloadFactory for MockFileDownloadFactory {
-        fn get_file_downloader(&self, _: &str, _: &str) -> Option<Arc<dyn FileDownload>> {
-            Some(self.file_download.clone())
-        }
+    #[test]
+    fn parse_should_install_the_runtimes_when_they_are_available() {
+        // Arrange
+        let mut mock_version_manager = MockVersionManager::new();
+        let runtime = RuntimeConfiguration {
+            version_manager: "mise".to_string(),
+            version: "node@20".to_string(),
+            default: true,
+            ..Default::default()
+        };
+        let expected_runtime = runtime.clone();
+
+        // Setup expectations
+        mock_version_manager.expect_install()
+            .once()
+            .withf(move |args| args.eq(&expected_runtime))
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            version_manager_factory: Arc::new(MockVersionManagerFactory { version_manager: Arc::new(mock_version_manager) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            runtimes: Some(vec!(runtime)),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
     }
-}
 
+    #[test]
+    fn parse_should_configure_the_services_when_they_are_available() {
+        // Arrange
+        let mut mock_service_manager = MockServiceManager::new();
+        let service = ServiceConfiguration {
+            name: "syncthing.service".to_string(),
+            enabled: Some(true),
+            ..Default::default()
+        };
+        let expected_services = vec!(service.clone());
+
+        // Setup expectations
+        mock_service_manager.expect_configure()
+            .once()
+            .withf(move |args| args.eq(&expected_services))
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            service_manager_factory: Arc::new(MockServiceManagerFactory { service_manager: Arc::new(mock_service_manager) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            services: Some(vec!(service)),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn parse_should_edit_the_lines_in_files_when_they_are_available() {
+        // Arrange
+        let mut mock_line_editor = MockLineEditor::new();
+        let line = LineInFileConfiguration {
+            path: "/etc/hosts".to_string(),
+            line: Some("127.0.0.1 upset.local".to_string()),
+            ..Default::default()
+        };
+        let expected_lines = vec!(line.clone());
+
+        // Setup expectations
+        mock_line_editor.expect_edit()
+            .once()
+            .withf(move |args| args.eq(&expected_lines))
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            line_editor_factory: Arc::new(MockLineEditorFactory { line_editor: Arc::new(mock_line_editor) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            line_in_file: Some(vec!(line)),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn parse_should_set_the_environment_when_it_is_available() {
+        // Arrange
+        let mut mock_environment_manager = MockEnvironmentManager::new();
+        let environment = EnvironmentConfiguration {
+            variables: BTreeMap::from([("EDITOR".to_string(), "nvim".to_string())]),
+            path: vec!("~/bin".to_string())
+        };
+        let expected_environment = environment.clone();
+
+        // Setup expectations
+        mock_environment_manager.expect_set()
+            .once()
+            .withf(move |args| args.eq(&expected_environment))
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            environment_manager_factory: Arc::new(MockEnvironmentManagerFactory { environment_manager: Arc::new(mock_environment_manager) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            environment: Some(environment),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn parse_should_create_the_directories_before_the_version_control_systems() {
+        // Arrange
+        let mut mock_version_control_system = MockVersionControlSystem::new();
+        let mut mock_directory_creator = MockDirectoryCreator::new();
+        let mut sequence = Sequence::new();
+        let directories = vec!(
+            DirectoryConfiguration::from("~/Git-projects".to_string()),
+            DirectoryConfiguration {
+                path: "~/.ssh".to_string(),
+                mode: Some(0o700),
+                ..Default::default()
+            }
+        );
+        let expected_directories = directories.clone();
+
+        // Setup expectations
+        mock_directory_creator.expect_create()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf(move |args| args.eq(&expected_directories))
+            .returning(|_| {});
+        mock_version_control_system.expect_download()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            version_control_system_factory: Arc::new(MockVersionControlSystemFactory { version_control: Arc::new(mock_version_control_system) }),
+            directory_creator_factory: Arc::new(MockDirectoryCreatorFactory { directory_creator: Arc::new(mock_directory_creator) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            directories: Some(directories),
+            version_control: Some(vec!(
+                VersionControlConfiguration {
+                    vcs: "git".to_string(),
+                    destination_folder: "~/Git-projects".to_string(),
+                    repositories: vec!("git@github.com:bartkessels/upset.git".to_string())
+                }
+            )),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn parse_should_configure_ssh_before_the_version_control_systems() {
+        // Arrange
+        let mut mock_version_control_system = MockVersionControlSystem::new();
+        let mut mock_ssh_manager = MockSshManager::new();
+        let mut sequence = Sequence::new();
+        let ssh = SshConfiguration {
+            keys: vec!(SshKeyConfiguration::default()),
+            known_hosts: vec!(KnownHostConfiguration::from(
+                "github.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl".to_string()
+            )),
+            ..Default::default()
+        };
+        let expected_ssh = ssh.clone();
+
+        // Setup expectations
+        mock_ssh_manager.expect_configure()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf(move |args| args.eq(&expected_ssh))
+            .returning(|_| {});
+        mock_version_control_system.expect_download()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+
+        let factories = ParserFactories {
+            version_control_system_factory: Arc::new(MockVersionControlSystemFactory { version_control: Arc::new(mock_version_control_system) }),
+            ssh_manager_factory: Arc::new(MockSshManagerFactory { ssh_manager: Arc::new(mock_ssh_manager) }),
+            ..create_parser_factories()
+        };
+
+        let config = Configuration {
+            ssh: Some(ssh),
+            version_control: Some(vec!(
+                VersionControlConfiguration {
+                    vcs: "git".to_string(),
+                    destination_folder: "~/Git-projects".to_string(),
+                    repositories: vec!("git@github.com:bartkessels/upset.git".to_string())
+                }
+            )),
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+
+    #[test]
+    fn should_not_parse_anything_when_the_configuration_is_empty() {
+        // Arrange
+        // The default factories return mocks without expectations, which fail when they are used
+        let factories = create_parser_factories();
+
+        let config = Configuration {
+            packages: None,
+            version_control: None,
+            downloads: None,
+            ..Default::default()
+        };
+
+        // Act
+        let sut = Version100Parser::new(&factories);
+        _ = sut.parse(&config);
+    }
+}
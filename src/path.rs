@@ -0,0 +1,174 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Expand a leading `~` in the path to the home directory
+/// of the current user
+///
+/// Returns the path untouched when it doesn't start with `~`
+/// or when the home directory can't be determined
+pub fn expand_home_directory(path: &str) -> String {
+    if path != "~" && !path.starts_with("~/") && !path.starts_with("~\\") {
+        return path.to_string();
+    }
+
+    return match home_directory() {
+        Some(home_directory) => format!("{}{}", home_directory, &path[1..]),
+        None => path.to_string()
+    };
+}
+
+/// Get the home directory of the current user
+pub fn home_directory() -> Option<String> {
+    return env::var("HOME")
+        .or_else(|_| env::var("USERPROFILE"))
+        .ok();
+}
+
+/// Get the location for a file at the destination
+///
+/// When the destination is a folder, or ends with a path separator,
+/// the file is placed inside of it using the name of the source
+pub fn resolve_destination(destination: &str, source: &Path) -> PathBuf {
+    let is_folder = destination.ends_with('/')
+        || destination.ends_with('\\')
+        || fs::metadata(&destination).is_ok_and(|metadata| metadata.is_dir());
+
+    return match (is_folder, source.file_name()) {
+        (true, Some(name)) => Path::new(&destination).join(name),
+        _ => PathBuf::from(&destination)
+    };
+}
+
+/// Get the location where a backup of the path is kept
+pub fn backup_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".bak");
+
+    return PathBuf::from(backup);
+}
+
+/// Create a symbolic link pointing to the target
+#[cfg(unix)]
+pub fn create_symlink(target: &Path, link: &Path) -> io::Result<()> {
+    return std::os::unix::fs::symlink(&target, &link);
+}
+
+/// Create a symbolic link pointing to the target, Windows
+/// has different kinds of links for files and folders
+#[cfg(windows)]
+pub fn create_symlink(target: &Path, link: &Path) -> io::Result<()> {
+    if target.is_dir() {
+        return std::os::windows::fs::symlink_dir(&target, &link);
+    }
+
+    return std::os::windows::fs::symlink_file(&target, &link);
+}
+
+/// Set the permissions of the file or folder
+#[cfg(unix)]
+pub fn set_mode(path: &Path, mode: u32) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    return fs::set_permissions(&path, fs::Permissions::from_mode(mode));
+}
+
+/// Permissions can't be expressed as a mode on Windows
+#[cfg(not(unix))]
+pub fn set_mode(_: &Path, _: u32) -> io::Result<()> {
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::path::Path;
+    use crate::path::{backup_path, expand_home_directory, home_directory, resolve_destination};
+
+    #[test]
+    fn expand_home_directory_replaces_the_tilde_with_the_home_directory() {
+        // Arrange
+        let path = "~/Downloads";
+        let home_directory = home_directory().unwrap();
+
+        // Act
+        let result = expand_home_directory(&path);
+
+        // Assert
+        assert_eq!(format!("{}/Downloads", home_directory), result);
+    }
+
+    #[test]
+    fn expand_home_directory_returns_the_home_directory_for_only_a_tilde() {
+        // Arrange
+        let path = "~";
+        let home_directory = home_directory().unwrap();
+
+        // Act
+        let result = expand_home_directory(&path);
+
+        // Assert
+        assert_eq!(home_directory, result);
+    }
+
+    #[test]
+    fn expand_home_directory_does_not_change_paths_without_a_leading_tilde() {
+        // Arrange
+        let path = "/tmp/~/Downloads";
+
+        // Act
+        let result = expand_home_directory(&path);
+
+        // Assert
+        assert_eq!(path, result);
+    }
+
+    #[test]
+    fn expand_home_directory_does_not_change_paths_for_other_users() {
+        // Arrange
+        let path = "~someone/Downloads";
+
+        // Act
+        let result = expand_home_directory(&path);
+
+        // Assert
+        assert_eq!(path, result);
+    }
+
+    #[test]
+    fn resolve_destination_uses_the_name_of_the_source_for_folders() {
+        // Arrange
+        let source = Path::new("/etc/upset/config.yaml");
+        let folder = env::temp_dir();
+
+        // Act
+        let with_separator = resolve_destination("/tmp/upset/", &source);
+        let existing_folder = resolve_destination(&folder.to_string_lossy(), &source);
+
+        // Assert
+        assert_eq!(Path::new("/tmp/upset/config.yaml"), with_separator);
+        assert_eq!(folder.join("config.yaml"), existing_folder);
+    }
+
+    #[test]
+    fn resolve_destination_returns_the_destination_for_files() {
+        // Arrange
+        let source = Path::new("/etc/upset/config.yaml");
+
+        // Act
+        let result = resolve_destination("/tmp/upset.yaml", &source);
+
+        // Assert
+        assert_eq!(Path::new("/tmp/upset.yaml"), result);
+    }
+
+    #[test]
+    fn backup_path_adds_the_bak_extension() {
+        // Act
+        let result = backup_path(Path::new("/home/upset/.gitconfig"));
+
+        // Assert
+        assert_eq!(Path::new("/home/upset/.gitconfig.bak"), result);
+    }
+}
@@ -0,0 +1,173 @@
+use std::any::Any;
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::RuntimeConfiguration;
+use crate::runtime::version_manager::VersionManager;
+use crate::terminal::TerminalOutput;
+
+/// Installs toolchains of any language supported by mise
+pub struct MiseVersionManager {
+    /// The mise command
+    mise_command: Arc<dyn Command>,
+
+    /// Terminal output where the installation status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl VersionManager for MiseVersionManager {
+    fn install(&self, runtime: &RuntimeConfiguration) {
+        // Components and targets are rustup concepts which mise has no equivalent for
+        if !runtime.components.is_empty() || !runtime.targets.is_empty() {
+            self.terminal_output.finish_with_warning(&format!(
+                "Ignoring the components and targets of {}, they are only supported by rustup",
+                &runtime.version
+            ));
+        }
+
+        self.terminal_output.loading(&format!("Installing {}", &runtime.version));
+
+        // Using a toolchain globally also installs it
+        let arguments = match runtime.default {
+            true => ["use".to_string(), "-g".to_string(), runtime.version.to_string()].to_vec(),
+            false => ["install".to_string(), runtime.version.to_string()].to_vec()
+        };
+        let is_success = self.mise_command.execute(&arguments).is_ok_and(|result| result);
+
+        if is_success {
+            self.terminal_output.finish_with_success(&format!("Successfully installed {}", &runtime.version));
+        } else {
+            self.terminal_output.finish_with_warning(&format!("Unable to install {}", &runtime.version));
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl MiseVersionManager {
+    pub fn new(mise_command: &Arc<dyn Command>, terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn VersionManager> {
+        return Arc::new(Self {
+            mise_command: mise_command.clone(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::RuntimeConfiguration;
+    use crate::runtime::mise_version_manager::MiseVersionManager;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output;
+
+    #[test]
+    fn install_uses_the_toolchain_globally_when_it_is_the_default() {
+        // Arrange
+        let runtime = RuntimeConfiguration {
+            version_manager: "mise".to_string(),
+            version: "node@20".to_string(),
+            default: true,
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &["use".to_string(), "-g".to_string(), "node@20".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = MiseVersionManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_terminal_output()
+        );
+        sut.install(&runtime);
+    }
+
+    #[test]
+    fn install_only_installs_the_toolchain_when_it_is_not_the_default() {
+        // Arrange
+        let runtime = RuntimeConfiguration {
+            version_manager: "mise".to_string(),
+            version: "python@3.12".to_string(),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &["install".to_string(), "python@3.12".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = MiseVersionManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_terminal_output()
+        );
+        sut.install(&runtime);
+    }
+
+    #[test]
+    fn install_calls_finish_with_warning_when_components_or_targets_are_configured() {
+        // Arrange
+        let runtime = RuntimeConfiguration {
+            version_manager: "mise".to_string(),
+            version: "rust@1.80".to_string(),
+            components: vec!("clippy".to_string()),
+            targets: vec!("wasm32-unknown-unknown".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &["install".to_string(), "rust@1.80".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message.starts_with("Ignoring the components and targets"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = MiseVersionManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.install(&runtime);
+    }
+
+    #[test]
+    fn install_calls_finish_with_warning_when_the_command_returns_an_error() {
+        // Arrange
+        let runtime = RuntimeConfiguration {
+            version_manager: "mise".to_string(),
+            version: "node@20".to_string(),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute().returning(|_| Err(String::default()));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = MiseVersionManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.install(&runtime);
+    }
+}
@@ -0,0 +1,9 @@
+mod version_manager;
+mod rustup_version_manager;
+mod mise_version_manager;
+mod version_manager_factory;
+
+pub use version_manager::MockVersionManager;
+pub use version_manager::VersionManager;
+pub use version_manager_factory::VersionManagerFactory;
+pub use version_manager_factory::VersionManagerFactoryImpl;
\ No newline at end of file
@@ -0,0 +1,177 @@
+use std::any::Any;
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::RuntimeConfiguration;
+use crate::runtime::version_manager::VersionManager;
+use crate::terminal::TerminalOutput;
+
+/// Installs Rust toolchains, including their components and targets
+pub struct RustupVersionManager {
+    /// The rustup command
+    rustup_command: Arc<dyn Command>,
+
+    /// Terminal output where the installation status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl VersionManager for RustupVersionManager {
+    fn install(&self, runtime: &RuntimeConfiguration) {
+        self.terminal_output.loading(&format!("Installing {}", &runtime.version));
+
+        match self.install_toolchain(&runtime) {
+            Ok(()) => self.terminal_output.finish_with_success(
+                &format!("Successfully installed {}", &runtime.version)
+            ),
+            Err(error) => self.terminal_output.finish_with_warning(
+                &format!("Unable to install {}, {}", &runtime.version, &error)
+            )
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl RustupVersionManager {
+    pub fn new(rustup_command: &Arc<dyn Command>, terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn VersionManager> {
+        return Arc::new(Self {
+            rustup_command: rustup_command.clone(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    /// Install the toolchain and add its components and targets,
+    /// rustup leaves everything which is already installed untouched
+    fn install_toolchain(&self, runtime: &RuntimeConfiguration) -> Result<(), String> {
+        self.rustup(&["toolchain", "install", &runtime.version])?;
+
+        if !runtime.components.is_empty() {
+            let mut arguments = vec!("component", "add", "--toolchain", runtime.version.as_str());
+            arguments.extend(runtime.components.iter().map(|component| component.as_str()));
+            self.rustup(&arguments)?;
+        }
+
+        if !runtime.targets.is_empty() {
+            let mut arguments = vec!("target", "add", "--toolchain", runtime.version.as_str());
+            arguments.extend(runtime.targets.iter().map(|target| target.as_str()));
+            self.rustup(&arguments)?;
+        }
+
+        if runtime.default {
+            self.rustup(&["default", &runtime.version])?;
+        }
+
+        return Ok(());
+    }
+
+    fn rustup(&self, arguments: &[&str]) -> Result<(), String> {
+        let arguments: Vec<String> = arguments.iter().map(|argument| argument.to_string()).collect();
+
+        if !self.rustup_command.execute(&arguments)? {
+            return Err(format!("rustup {} failed", &arguments[0..2].join(" ")));
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use mockall::Sequence;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::RuntimeConfiguration;
+    use crate::runtime::rustup_version_manager::RustupVersionManager;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output;
+
+    #[test]
+    fn install_installs_the_toolchain_with_its_components_and_targets() {
+        // Arrange
+        let runtime = RuntimeConfiguration {
+            version_manager: "rustup".to_string(),
+            version: "stable".to_string(),
+            default: true,
+            components: vec!("clippy".to_string(), "rustfmt".to_string()),
+            targets: vec!("wasm32-unknown-unknown".to_string())
+        };
+        let mut command_mock = MockCommand::new();
+        let mut sequence = Sequence::new();
+
+        // Setup the expectation
+        for arguments in [
+            vec!("toolchain", "install", "stable"),
+            vec!("component", "add", "--toolchain", "stable", "clippy", "rustfmt"),
+            vec!("target", "add", "--toolchain", "stable", "wasm32-unknown-unknown"),
+            vec!("default", "stable")
+        ] {
+            command_mock.expect_execute()
+                .withf(move |args| args == arguments.as_slice())
+                .once()
+                .in_sequence(&mut sequence)
+                .returning(|_| Ok(true));
+        }
+
+        // Act
+        let sut = RustupVersionManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_terminal_output()
+        );
+        sut.install(&runtime);
+    }
+
+    #[test]
+    fn install_only_installs_the_toolchain_when_nothing_else_is_configured() {
+        // Arrange
+        let runtime = RuntimeConfiguration {
+            version_manager: "rustup".to_string(),
+            version: "nightly".to_string(),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &["toolchain".to_string(), "install".to_string(), "nightly".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = RustupVersionManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_terminal_output()
+        );
+        sut.install(&runtime);
+    }
+
+    #[test]
+    fn install_calls_finish_with_warning_when_the_toolchain_can_not_be_installed() {
+        // Arrange
+        let runtime = RuntimeConfiguration {
+            version_manager: "rustup".to_string(),
+            version: "stable".to_string(),
+            default: true,
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .once()
+            .returning(|_| Ok(false));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message == "Unable to install stable, rustup toolchain install failed")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = RustupVersionManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.install(&runtime);
+    }
+}
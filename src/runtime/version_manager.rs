@@ -0,0 +1,13 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::RuntimeConfiguration;
+
+/// Public methods to call on a specific version manager
+#[automock]
+pub trait VersionManager {
+    /// Install the toolchain
+    fn install(&self, runtime: &RuntimeConfiguration);
+
+    /// Get object reference
+    fn as_any(&self) -> &dyn Any;
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use crate::commands::{MiseCommand, RustupCommand};
+use crate::runtime::mise_version_manager::MiseVersionManager;
+use crate::runtime::rustup_version_manager::RustupVersionManager;
+use crate::runtime::version_manager::VersionManager;
+use crate::terminal::TerminalOutput;
+
+pub trait VersionManagerFactory {
+    fn get_version_manager(&self, name: &String) -> Option<Arc<dyn VersionManager>>;
+}
+
+pub struct VersionManagerFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl VersionManagerFactory for VersionManagerFactoryImpl {
+    fn get_version_manager(&self, name: &String) -> Option<Arc<dyn VersionManager>> {
+        return match name.to_lowercase().as_str() {
+            "rustup" => Some(RustupVersionManager::new(&RustupCommand::new(), &self.terminal_output)),
+            "mise" => Some(MiseVersionManager::new(&MiseCommand::new(), &self.terminal_output)),
+            _ => None
+        };
+    }
+}
+
+impl VersionManagerFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn VersionManagerFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::runtime::mise_version_manager::MiseVersionManager;
+    use crate::runtime::rustup_version_manager::RustupVersionManager;
+    use crate::runtime::version_manager_factory::VersionManagerFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[test]
+    fn get_version_manager_returns_none_for_unsupported_version_manager() {
+        // Arrange
+        let name = "not-supported-version-manager".to_string();
+        let terminal_output = MockTerminalOutput::new();
+
+        // Act
+        let sut = VersionManagerFactoryImpl::new(&(Arc::new(terminal_output) as Arc<dyn TerminalOutput>));
+        let result = sut.get_version_manager(&name);
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn get_version_manager_returns_rustup_for_rustup_version_manager() {
+        // Arrange
+        let name = "rustup".to_string();
+        let terminal_output = MockTerminalOutput::new();
+
+        // Act
+        let sut = VersionManagerFactoryImpl::new(&(Arc::new(terminal_output) as Arc<dyn TerminalOutput>));
+        let result = sut.get_version_manager(&name);
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<RustupVersionManager>());
+    }
+
+    #[test]
+    fn get_version_manager_returns_mise_for_mise_version_manager() {
+        // Arrange
+        let name = "mise".to_string();
+        let terminal_output = MockTerminalOutput::new();
+
+        // Act
+        let sut = VersionManagerFactoryImpl::new(&(Arc::new(terminal_output) as Arc<dyn TerminalOutput>));
+        let result = sut.get_version_manager(&name);
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<MiseVersionManager>());
+    }
+}
@@ -0,0 +1,345 @@
+use std::any::Any;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::command::Command;
+use crate::configuration::ScheduleConfiguration;
+use crate::managed_block::set_managed_block;
+use crate::schedule::scheduler::Scheduler;
+use crate::terminal::TerminalOutput;
+
+pub struct CronScheduler {
+    /// The crontab command
+    crontab_command: Arc<dyn Command>,
+
+    /// Folder where the new crontab is written to before it's installed
+    temp_folder: String,
+
+    /// Terminal output where the schedule status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl Scheduler for CronScheduler {
+    fn schedule(&self, schedules: &Vec<ScheduleConfiguration>) {
+        for schedule in schedules {
+            self.schedule_command(&schedule);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl CronScheduler {
+    pub fn new(
+        crontab_command: &Arc<dyn Command>,
+        temp_folder: &str,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn Scheduler> {
+        return Arc::new(Self {
+            crontab_command: crontab_command.clone(),
+            temp_folder: temp_folder.to_string(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn schedule_command(&self, schedule: &ScheduleConfiguration) {
+        self.terminal_output.loading(&format!("Scheduling {}", &schedule.name));
+
+        let crontab = match self.read_crontab() {
+            Ok(crontab) => crontab,
+            Err(error) => {
+                self.terminal_output.finish_with_warning(
+                    &format!("Unable to schedule {}, {}", &schedule.name, &error)
+                );
+                return;
+            }
+        };
+        let entry = format!("{} {}", &schedule.schedule, &schedule.command);
+        let updated = set_managed_block(&crontab, "#", &schedule.name, Some(&entry));
+
+        if updated == crontab {
+            self.terminal_output.finish_with_success(&format!("{} is up to date", &schedule.name));
+            return;
+        }
+
+        match self.install(&updated) {
+            Ok(()) => self.terminal_output.finish_with_success(
+                &format!("Successfully scheduled {}", &schedule.name)
+            ),
+            Err(_) => self.terminal_output.finish_with_warning(
+                &format!("Unable to schedule {}", &schedule.name)
+            )
+        }
+    }
+
+    /// Read the crontab of the user, which is empty when the user doesn't
+    /// have a crontab yet
+    ///
+    /// Any other error is returned, so the existing entries are never
+    /// replaced by a crontab which only contains the managed blocks
+    fn read_crontab(&self) -> Result<String, String> {
+        return match self.crontab_command.output(&["-l".to_string()]) {
+            Ok(crontab) => Ok(crontab),
+            Err(error) if error.contains("no crontab for") => Ok(String::default()),
+            Err(error) => Err(error)
+        };
+    }
+
+    /// Replace the crontab of the user
+    fn install(&self, crontab: &str) -> Result<(), String> {
+        let crontab_file = create_crontab_file(Path::new(&self.temp_folder), &crontab)
+            .map_err(|e| e.to_string())?;
+        let result = self.crontab_command.execute(&[crontab_file.to_string_lossy().to_string()]);
+        _ = fs::remove_file(&crontab_file);
+
+        return match result {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("Unable to install the crontab".to_string()),
+            Err(error) => Err(error)
+        };
+    }
+}
+
+/// Write the crontab into a new file which only the current user can read
+///
+/// The file is created exclusively, so a file or link which another user
+/// has placed at the same location is never written to
+fn create_crontab_file(temp_folder: &Path, crontab: &str) -> std::io::Result<PathBuf> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let path = temp_folder.join(format!("upset-crontab-{}-{}", process::id(), timestamp));
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options.open(&path)?.write_all(crontab.as_bytes())?;
+
+    return Ok(path);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::ScheduleConfiguration;
+    use crate::schedule::cron_scheduler::CronScheduler;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    #[test]
+    fn schedule_adds_the_entry_to_the_crontab() {
+        // Arrange
+        let folder = create_test_folder("crontab", "add");
+        let expected_folder = folder.clone();
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_output()
+            .withf(|args| args == &["-l".to_string()])
+            .returning(|_| Ok("MAILTO=\"\"\n".to_string()));
+        command_mock.expect_execute()
+            .withf(move |args| {
+                PathBuf::from(&args[0]).starts_with(&expected_folder) && fs::read_to_string(&args[0]).unwrap()
+                    == "MAILTO=\"\"\n# BEGIN upset backup\n0 2 * * * ~/bin/backup.sh\n# END upset backup\n"
+            })
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CronScheduler::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &folder.to_string_lossy(),
+            &setup_terminal_output()
+        );
+        sut.schedule(&vec!(create_schedule()));
+
+        // Assert
+        assert_eq!(0, fs::read_dir(&folder).unwrap().count());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn schedule_writes_the_crontab_into_a_file_only_the_user_can_read() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange
+        let folder = create_test_folder("crontab", "permissions");
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_output().returning(|_| Ok(String::default()));
+        command_mock.expect_execute()
+            .withf(|args| fs::metadata(&args[0]).unwrap().permissions().mode() & 0o777 == 0o600)
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CronScheduler::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &folder.to_string_lossy(),
+            &setup_terminal_output()
+        );
+        sut.schedule(&vec!(create_schedule()));
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn schedule_calls_finish_with_success_when_the_entry_is_up_to_date() {
+        // Arrange
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_output()
+            .returning(|_| Ok("# BEGIN upset backup\n0 2 * * * ~/bin/backup.sh\n# END upset backup\n".to_string()));
+        command_mock.expect_execute().never();
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message == "backup is up to date")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CronScheduler::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &env::temp_dir().to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.schedule(&vec!(create_schedule()));
+    }
+
+    #[test]
+    fn schedule_creates_the_crontab_when_the_user_does_not_have_one() {
+        // Arrange
+        let folder = create_test_folder("crontab", "new");
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_output()
+            .returning(|_| Err("no crontab for bart".to_string()));
+        command_mock.expect_execute()
+            .withf(|args| {
+                fs::read_to_string(&args[0]).unwrap()
+                    == "# BEGIN upset backup\n0 2 * * * ~/bin/backup.sh\n# END upset backup\n"
+            })
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CronScheduler::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &folder.to_string_lossy(),
+            &setup_terminal_output()
+        );
+        sut.schedule(&vec!(create_schedule()));
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn schedule_creates_the_crontab_when_the_user_does_not_have_one_on_macos() {
+        // Arrange
+        let folder = create_test_folder("crontab", "new-macos");
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_output()
+            .returning(|_| Err("crontab: no crontab for bart".to_string()));
+        command_mock.expect_execute()
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = CronScheduler::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &folder.to_string_lossy(),
+            &setup_terminal_output()
+        );
+        sut.schedule(&vec!(create_schedule()));
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn schedule_leaves_the_crontab_alone_when_it_can_not_be_read() {
+        // Arrange
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_output()
+            .returning(|_| Err("crontab: Permission denied".to_string()));
+        command_mock.expect_execute().never();
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message == "Unable to schedule backup, crontab: Permission denied")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CronScheduler::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &env::temp_dir().to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.schedule(&vec!(create_schedule()));
+    }
+
+    #[test]
+    fn schedule_calls_finish_with_warning_when_the_crontab_can_not_be_installed() {
+        // Arrange
+        let folder = create_test_folder("crontab", "failed");
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_output().returning(|_| Ok(String::default()));
+        command_mock.expect_execute().returning(|_| Err(String::default()));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = CronScheduler::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.schedule(&vec!(create_schedule()));
+
+        // Assert
+        assert_eq!(0, fs::read_dir(&folder).unwrap().count());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn create_schedule() -> ScheduleConfiguration {
+        return ScheduleConfiguration {
+            name: "backup".to_string(),
+            schedule: "0 2 * * *".to_string(),
+            command: "~/bin/backup.sh".to_string()
+        };
+    }
+}
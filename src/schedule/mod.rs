@@ -0,0 +1,8 @@
+mod scheduler;
+mod cron_scheduler;
+mod scheduler_factory;
+
+pub use scheduler::MockScheduler;
+pub use scheduler::Scheduler;
+pub use scheduler_factory::SchedulerFactory;
+pub use scheduler_factory::SchedulerFactoryImpl;
\ No newline at end of file
@@ -0,0 +1,10 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::ScheduleConfiguration;
+
+/// Public methods to call for scheduling commands
+#[automock]
+pub trait Scheduler {
+    fn schedule(&self, schedules: &Vec<ScheduleConfiguration>);
+    fn as_any(&self) -> &dyn Any;
+}
@@ -0,0 +1,79 @@
+use std::env;
+use std::sync::Arc;
+use crate::commands::CrontabCommand;
+use crate::schedule::cron_scheduler::CronScheduler;
+use crate::schedule::scheduler::Scheduler;
+use crate::terminal::TerminalOutput;
+
+pub trait SchedulerFactory {
+    fn get_scheduler(&self) -> Option<Arc<dyn Scheduler>>;
+}
+
+pub struct SchedulerFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl SchedulerFactory for SchedulerFactoryImpl {
+    /// Commands are scheduled using cron, which is only available on Unix
+    fn get_scheduler(&self) -> Option<Arc<dyn Scheduler>> {
+        if !cfg!(unix) {
+            return None;
+        }
+
+        return Some(CronScheduler::new(
+            &CrontabCommand::new(),
+            &env::temp_dir().to_string_lossy(),
+            &self.terminal_output
+        ));
+    }
+}
+
+impl SchedulerFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn SchedulerFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::schedule::scheduler_factory::SchedulerFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[cfg(unix)]
+    #[test]
+    fn get_scheduler_returns_cron_scheduler() {
+        use crate::schedule::cron_scheduler::CronScheduler;
+
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = SchedulerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_scheduler();
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<CronScheduler>());
+    }
+
+    #[cfg(not(unix))]
+    #[test]
+    fn get_scheduler_returns_none_without_cron() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = SchedulerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_scheduler();
+
+        // Assert
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,518 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::ScriptConfiguration;
+use crate::path::expand_home_directory;
+use crate::script::script_runner::ScriptRunner;
+use crate::terminal::TerminalOutput;
+
+pub struct InterpreterScriptRunner {
+    /// The command of the interpreter
+    interpreter_command: Arc<dyn Command>,
+
+    /// Name of the interpreter, which determines how
+    /// scripts are passed to the interpreter command
+    interpreter: String,
+
+    /// Terminal output where the status of the script is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl ScriptRunner for InterpreterScriptRunner {
+    fn run(&self, script: &ScriptConfiguration) {
+        let name = get_name(&script);
+
+        if self.has_been_run(&script) {
+            self.terminal_output.finish_with_success(&format!("{} has already been run", &name));
+            return;
+        }
+
+        self.terminal_output.loading(&format!("Running {}", &name));
+
+        let result = match self.get_arguments(&script) {
+            Some(arguments) => self.interpreter_command.execute(&arguments),
+            None => Err(format!("{} has nothing to run", &name))
+        };
+        let is_success = result.is_ok_and(|result| result);
+
+        if is_success {
+            self.terminal_output.finish_with_success(&format!("Successfully ran {}", &name));
+        } else {
+            self.terminal_output.finish_with_warning(&format!("Unable to run {}", &name));
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl InterpreterScriptRunner {
+    pub fn new(
+        interpreter_command: &Arc<dyn Command>,
+        interpreter: &str,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn ScriptRunner> {
+        return Arc::new(Self {
+            interpreter_command: interpreter_command.clone(),
+            interpreter: interpreter.to_lowercase(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    /// Scripts have been run when the file they create already
+    /// exists, or when their `unless` command succeeds
+    fn has_been_run(&self, script: &ScriptConfiguration) -> bool {
+        if let Some(creates) = &script.creates {
+            if get_creates_path(&script, &creates).exists() {
+                return true;
+            }
+        }
+
+        return match &script.unless {
+            Some(unless) => self.interpreter_command
+                .execute(&self.get_inline_arguments(&unless))
+                .is_ok_and(|result| result),
+            None => false
+        };
+    }
+
+    fn get_arguments(&self, script: &ScriptConfiguration) -> Option<Vec<String>> {
+        if let Some(run) = &script.run {
+            return Some(self.get_inline_arguments(&run));
+        }
+
+        return script.path.as_ref()
+            .map(|path| self.get_file_arguments(&expand_home_directory(&path)));
+    }
+
+    fn get_inline_arguments(&self, body: &str) -> Vec<String> {
+        return match self.interpreter.as_str() {
+            "pwsh" | "powershell" => vec!(
+                "-NoProfile".to_string(),
+                "-NonInteractive".to_string(),
+                "-Command".to_string(),
+                body.to_string()
+            ),
+            _ => vec!("-c".to_string(), body.to_string())
+        };
+    }
+
+    fn get_file_arguments(&self, path: &str) -> Vec<String> {
+        return match self.interpreter.as_str() {
+            "pwsh" | "powershell" => vec!(
+                "-NoProfile".to_string(),
+                "-NonInteractive".to_string(),
+                "-File".to_string(),
+                path.to_string()
+            ),
+            _ => vec!(path.to_string())
+        };
+    }
+}
+
+/// Relative paths are resolved against the working directory of the
+/// script, which is where the script itself would have created them
+fn get_creates_path(script: &ScriptConfiguration, creates: &str) -> PathBuf {
+    let creates = PathBuf::from(expand_home_directory(&creates));
+
+    return match &script.working_directory {
+        Some(working_directory) if creates.is_relative() => {
+            Path::new(&expand_home_directory(&working_directory)).join(&creates)
+        },
+        _ => creates
+    };
+}
+
+/// Get the configured name of the script, falling back
+/// to its path or the first line of the inline script
+fn get_name(script: &ScriptConfiguration) -> String {
+    if let Some(name) = &script.name {
+        return name.to_string();
+    }
+
+    if let Some(path) = &script.path {
+        return path.to_string();
+    }
+
+    return script.run.as_ref()
+        .and_then(|run| run.lines().map(|line| line.trim()).find(|line| !line.is_empty()))
+        .unwrap_or("script")
+        .to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::sync::Arc;
+    use mockall::Sequence;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::ScriptConfiguration;
+    use crate::script::interpreter_script_runner::{get_name, InterpreterScriptRunner};
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output_mock;
+
+    #[test]
+    fn run_calls_the_interpreter_with_the_inline_script() {
+        // Arrange
+        let script = ScriptConfiguration {
+            run: Some("echo upset".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &["-c".to_string(), "echo upset".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "bash",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+    }
+
+    #[test]
+    fn run_calls_the_interpreter_with_the_script_file() {
+        // Arrange
+        let script = ScriptConfiguration {
+            path: Some("/tmp/upset.py".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &["/tmp/upset.py".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "python",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+    }
+
+    #[test]
+    fn run_calls_powershell_with_the_command_and_file_arguments() {
+        // Arrange
+        let inline_script = ScriptConfiguration {
+            run: Some("Write-Output upset".to_string()),
+            ..Default::default()
+        };
+        let file_script = ScriptConfiguration {
+            path: Some("C:/Scripts/upset.ps1".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectations
+        command_mock.expect_execute()
+            .withf(|args| args == &[
+                "-NoProfile".to_string(),
+                "-NonInteractive".to_string(),
+                "-Command".to_string(),
+                "Write-Output upset".to_string()
+            ])
+            .once()
+            .returning(|_| Ok(true));
+        command_mock.expect_execute()
+            .withf(|args| args == &[
+                "-NoProfile".to_string(),
+                "-NonInteractive".to_string(),
+                "-File".to_string(),
+                "C:/Scripts/upset.ps1".to_string()
+            ])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "pwsh",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&inline_script);
+        sut.run(&file_script);
+    }
+
+    #[test]
+    fn run_calls_windows_powershell_with_the_command_arguments() {
+        // Arrange
+        let script = ScriptConfiguration {
+            run: Some("Write-Output upset".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &[
+                "-NoProfile".to_string(),
+                "-NonInteractive".to_string(),
+                "-Command".to_string(),
+                "Write-Output upset".to_string()
+            ])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "powershell",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+    }
+
+    #[test]
+    fn run_does_not_call_the_interpreter_when_the_created_file_exists() {
+        // Arrange
+        let script = ScriptConfiguration {
+            run: Some("mkdir /tmp".to_string()),
+            creates: Some(env::temp_dir().to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        command_mock.expect_execute()
+            .never()
+            .returning(|_| Ok(true));
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("has already been run"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "sh",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+    }
+
+    #[test]
+    fn run_resolves_the_created_file_against_the_working_directory() {
+        // Arrange
+        let working_directory = env::temp_dir().join("upset-script-creates");
+        fs::create_dir_all(working_directory.join("target")).expect("Unable to create the created folder");
+        let script = ScriptConfiguration {
+            run: Some("cargo build".to_string()),
+            working_directory: Some(working_directory.to_string_lossy().to_string()),
+            creates: Some("target".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        command_mock.expect_execute()
+            .never()
+            .returning(|_| Ok(true));
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("has already been run"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "sh",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+
+        // Teardown
+        fs::remove_dir_all(&working_directory).expect("Unable to delete the working directory");
+    }
+
+    #[test]
+    fn run_does_not_run_the_script_when_the_unless_command_succeeds() {
+        // Arrange
+        let script = ScriptConfiguration {
+            run: Some("install-upset".to_string()),
+            unless: Some("command -v upset".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        command_mock.expect_execute()
+            .withf(|args| args == &["-c".to_string(), "command -v upset".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+        command_mock.expect_execute()
+            .withf(|args| args == &["-c".to_string(), "install-upset".to_string()])
+            .never()
+            .returning(|_| Ok(true));
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("has already been run"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "sh",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+    }
+
+    #[test]
+    fn run_runs_the_script_when_the_unless_command_fails() {
+        // Arrange
+        let script = ScriptConfiguration {
+            run: Some("install-upset".to_string()),
+            unless: Some("command -v upset".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let terminal_output_mock = setup_terminal_output_mock();
+
+        // Setup the expectations
+        let mut sequence = Sequence::new();
+
+        command_mock.expect_execute()
+            .withf(|args| args == &["-c".to_string(), "command -v upset".to_string()])
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(false));
+        command_mock.expect_execute()
+            .withf(|args| args == &["-c".to_string(), "install-upset".to_string()])
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "sh",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+    }
+
+    #[test]
+    fn run_calls_loading_on_the_terminal_output_before_the_script_is_run() {
+        // Arrange
+        let script = ScriptConfiguration {
+            run: Some("echo upset".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectations
+        let mut sequence = Sequence::new();
+
+        terminal_output_mock.expect_loading()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+        command_mock.expect_execute()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(true));
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message == "Successfully ran echo upset")
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| {});
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "sh",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+    }
+
+    #[test]
+    fn run_calls_finish_with_warning_when_the_script_fails() {
+        // Arrange
+        let script = ScriptConfiguration {
+            run: Some("exit 1".to_string()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the mocks
+        command_mock.expect_execute().returning(|_| Ok(false));
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "sh",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+    }
+
+    #[test]
+    fn run_calls_finish_with_warning_when_there_is_nothing_to_run() {
+        // Arrange
+        let script = ScriptConfiguration::default();
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .never()
+            .returning(|_| Ok(true));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = InterpreterScriptRunner::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            "sh",
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.run(&script);
+    }
+
+    #[test]
+    fn get_name_falls_back_to_the_path_or_the_first_line_of_the_script() {
+        assert_eq!("Install rust", get_name(&ScriptConfiguration {
+            name: Some("Install rust".to_string()),
+            path: Some("~/install-rust.sh".to_string()),
+            ..Default::default()
+        }));
+        assert_eq!("~/install-rust.sh", get_name(&ScriptConfiguration {
+            path: Some("~/install-rust.sh".to_string()),
+            ..Default::default()
+        }));
+        assert_eq!("curl https://sh.rustup.rs | sh", get_name(&ScriptConfiguration {
+            run: Some("\n  curl https://sh.rustup.rs | sh\n  rustup default stable\n".to_string()),
+            ..Default::default()
+        }));
+    }
+}
@@ -0,0 +1,8 @@
+mod script_runner;
+mod interpreter_script_runner;
+mod script_runner_factory;
+
+pub use script_runner::MockScriptRunner;
+pub use script_runner::ScriptRunner;
+pub use script_runner_factory::ScriptRunnerFactory;
+pub use script_runner_factory::ScriptRunnerFactoryImpl;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::ScriptConfiguration;
+
+/// Public methods to call on a specific script interpreter
+#[automock]
+pub trait ScriptRunner {
+    /// Execute the script unless its guards indicate it has already been run
+    fn run(&self, script: &ScriptConfiguration);
+
+    /// Get object reference
+    fn as_any(&self) -> &dyn Any;
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use crate::commands::InterpreterCommand;
+use crate::configuration::ScriptConfiguration;
+use crate::path::expand_home_directory;
+use crate::script::interpreter_script_runner::InterpreterScriptRunner;
+use crate::script::script_runner::ScriptRunner;
+use crate::terminal::TerminalOutput;
+
+/// Windows doesn't ship with a POSIX shell, nor with PowerShell 7 (`pwsh`)
+#[cfg(windows)]
+const DEFAULT_INTERPRETER: &str = "powershell";
+#[cfg(not(windows))]
+const DEFAULT_INTERPRETER: &str = "sh";
+
+pub trait ScriptRunnerFactory {
+    fn get_script_runner(&self, script: &ScriptConfiguration) -> Option<Arc<dyn ScriptRunner>>;
+}
+
+pub struct ScriptRunnerFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl ScriptRunnerFactory for ScriptRunnerFactoryImpl {
+    fn get_script_runner(&self, script: &ScriptConfiguration) -> Option<Arc<dyn ScriptRunner>> {
+        let interpreter = script.interpreter.as_deref().unwrap_or(DEFAULT_INTERPRETER);
+        let working_directory = script.working_directory.as_ref()
+            .map(|working_directory| expand_home_directory(&working_directory));
+
+        return match interpreter.to_lowercase().as_str() {
+            "sh" | "bash" | "pwsh" | "powershell" | "python" => Some(InterpreterScriptRunner::new(
+                &InterpreterCommand::new(&interpreter, &working_directory, &script.environment),
+                &interpreter,
+                &self.terminal_output
+            )),
+            _ => None
+        };
+    }
+}
+
+impl ScriptRunnerFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn ScriptRunnerFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::configuration::ScriptConfiguration;
+    use crate::script::interpreter_script_runner::InterpreterScriptRunner;
+    use crate::script::script_runner_factory::ScriptRunnerFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[test]
+    fn get_script_runner_returns_none_for_unsupported_interpreter() {
+        // Arrange
+        let script = ScriptConfiguration {
+            interpreter: Some("cobol".to_string()),
+            ..Default::default()
+        };
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = ScriptRunnerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_script_runner(&script);
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn get_script_runner_returns_interpreter_for_supported_interpreters() {
+        for interpreter in ["sh", "bash", "pwsh", "powershell", "python"] {
+            // Arrange
+            let script = ScriptConfiguration {
+                interpreter: Some(interpreter.to_string()),
+                ..Default::default()
+            };
+            let terminal_output_mock = MockTerminalOutput::new();
+
+            // Act
+            let sut = ScriptRunnerFactoryImpl::new(
+                &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+            );
+            let result = sut.get_script_runner(&script);
+
+            // Assert
+            assert!(result.is_some());
+            assert!(result.unwrap().as_any().is::<InterpreterScriptRunner>());
+        }
+    }
+
+    #[test]
+    fn get_script_runner_returns_interpreter_when_no_interpreter_is_configured() {
+        // Arrange
+        let script = ScriptConfiguration::default();
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = ScriptRunnerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_script_runner(&script);
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<InterpreterScriptRunner>());
+    }
+}
@@ -0,0 +1,8 @@
+mod service_manager;
+mod systemd_service_manager;
+mod service_manager_factory;
+
+pub use service_manager::MockServiceManager;
+pub use service_manager::ServiceManager;
+pub use service_manager_factory::ServiceManagerFactory;
+pub use service_manager_factory::ServiceManagerFactoryImpl;
\ No newline at end of file
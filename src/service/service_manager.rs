@@ -0,0 +1,10 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::ServiceConfiguration;
+
+/// Public methods to call for configuring services
+#[automock]
+pub trait ServiceManager {
+    fn configure(&self, services: &Vec<ServiceConfiguration>);
+    fn as_any(&self) -> &dyn Any;
+}
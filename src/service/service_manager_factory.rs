@@ -0,0 +1,85 @@
+use std::path::Path;
+use std::sync::Arc;
+use crate::commands::SystemctlCommand;
+use crate::path::home_directory;
+use crate::service::service_manager::ServiceManager;
+use crate::service::systemd_service_manager::SystemdServiceManager;
+use crate::terminal::TerminalOutput;
+
+const SYSTEM_UNIT_FOLDER: &str = "/etc/systemd/system";
+
+pub trait ServiceManagerFactory {
+    fn get_service_manager(&self) -> Option<Arc<dyn ServiceManager>>;
+}
+
+pub struct ServiceManagerFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl ServiceManagerFactory for ServiceManagerFactoryImpl {
+    /// Services are managed by systemd, which is only available on Linux
+    fn get_service_manager(&self) -> Option<Arc<dyn ServiceManager>> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+
+        let user_unit_folder = Path::new(&home_directory()?).join(".config/systemd/user");
+
+        return Some(SystemdServiceManager::new(
+            &SystemctlCommand::new(),
+            &user_unit_folder.to_string_lossy(),
+            &SYSTEM_UNIT_FOLDER,
+            &self.terminal_output
+        ));
+    }
+}
+
+impl ServiceManagerFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn ServiceManagerFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::service::service_manager_factory::ServiceManagerFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn get_service_manager_returns_systemd_service_manager() {
+        use crate::service::systemd_service_manager::SystemdServiceManager;
+
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = ServiceManagerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_service_manager();
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<SystemdServiceManager>());
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn get_service_manager_returns_none_without_systemd() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = ServiceManagerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_service_manager();
+
+        // Assert
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,292 @@
+use std::any::Any;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use crate::command::Command;
+use crate::configuration::{ServiceConfiguration, ServiceScope, ServiceState};
+use crate::path::expand_home_directory;
+use crate::service::service_manager::ServiceManager;
+use crate::terminal::TerminalOutput;
+
+pub struct SystemdServiceManager {
+    /// The systemctl command
+    systemctl_command: Arc<dyn Command>,
+
+    /// Folder containing the units of the user, e.g. `~/.config/systemd/user`
+    user_unit_folder: String,
+
+    /// Folder containing the units of the system, e.g. `/etc/systemd/system`
+    system_unit_folder: String,
+
+    /// Terminal output where the status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl ServiceManager for SystemdServiceManager {
+    fn configure(&self, services: &Vec<ServiceConfiguration>) {
+        for service in services {
+            self.configure_service(&service);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl SystemdServiceManager {
+    pub fn new(
+        systemctl_command: &Arc<dyn Command>,
+        user_unit_folder: &str,
+        system_unit_folder: &str,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn ServiceManager> {
+        return Arc::new(Self {
+            systemctl_command: systemctl_command.clone(),
+            user_unit_folder: user_unit_folder.to_string(),
+            system_unit_folder: system_unit_folder.to_string(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn configure_service(&self, service: &ServiceConfiguration) {
+        self.terminal_output.loading(&format!("Configuring {}", &service.name));
+
+        match self.apply(&service) {
+            Ok(()) => self.terminal_output.finish_with_success(
+                &format!("Successfully configured {}", &service.name)
+            ),
+            Err(error) => self.terminal_output.finish_with_warning(
+                &format!("Unable to configure {}, {}", &service.name, &error)
+            )
+        }
+    }
+
+    /// Write the unit file and bring the unit in the configured state,
+    /// a running unit is restarted when its unit file has changed
+    fn apply(&self, service: &ServiceConfiguration) -> Result<(), String> {
+        let changed = self.write_unit(&service)?;
+
+        if changed {
+            self.systemctl(&service.scope, &["daemon-reload"])?;
+        }
+
+        match service.enabled {
+            Some(true) => self.systemctl(&service.scope, &["enable", &service.name])?,
+            Some(false) => self.systemctl(&service.scope, &["disable", &service.name])?,
+            None => ()
+        }
+
+        return match service.state {
+            Some(ServiceState::Started) if changed => self.systemctl(&service.scope, &["restart", &service.name]),
+            Some(ServiceState::Started) => self.systemctl(&service.scope, &["start", &service.name]),
+            Some(ServiceState::Stopped) => self.systemctl(&service.scope, &["stop", &service.name]),
+            None => Ok(())
+        };
+    }
+
+    /// Write the unit file into the unit folder of the scope
+    ///
+    /// Returns whether the unit file has changed
+    fn write_unit(&self, service: &ServiceConfiguration) -> Result<bool, String> {
+        let contents = match (&service.unit, &service.source) {
+            (Some(unit), _) => unit.to_string(),
+            (None, Some(source)) => fs::read_to_string(expand_home_directory(&source)).map_err(|e| e.to_string())?,
+            (None, None) => return Ok(false)
+        };
+        let unit_folder = match service.scope {
+            ServiceScope::User => &self.user_unit_folder,
+            ServiceScope::System => &self.system_unit_folder
+        };
+        let path = Path::new(&unit_folder).join(&service.name);
+
+        if fs::read_to_string(&path).is_ok_and(|existing| existing == contents) {
+            return Ok(false);
+        }
+
+        fs::create_dir_all(&unit_folder).map_err(|e| e.to_string())?;
+        fs::write(&path, &contents).map_err(|e| e.to_string())?;
+
+        return Ok(true);
+    }
+
+    fn systemctl(&self, scope: &ServiceScope, arguments: &[&str]) -> Result<(), String> {
+        let mut command_arguments = Vec::new();
+
+        if *scope == ServiceScope::User {
+            command_arguments.push("--user".to_string());
+        }
+
+        command_arguments.extend(arguments.iter().map(|argument| argument.to_string()));
+
+        if !self.systemctl_command.execute(&command_arguments)? {
+            return Err(format!("systemctl {} failed", &arguments[0]));
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use mockall::Sequence;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::{ServiceConfiguration, ServiceScope, ServiceState};
+    use crate::service::service_manager::ServiceManager;
+    use crate::service::systemd_service_manager::SystemdServiceManager;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    const UNIT: &str = "[Service]\nExecStart=/usr/bin/syncthing serve\n";
+
+    #[test]
+    fn configure_writes_the_unit_and_reloads_before_enabling_and_starting() {
+        // Arrange
+        let folder = create_test_folder("service", "write");
+        let services = vec!(ServiceConfiguration {
+            name: "syncthing.service".to_string(),
+            unit: Some(UNIT.to_string()),
+            enabled: Some(true),
+            state: Some(ServiceState::Started),
+            ..Default::default()
+        });
+        let mut command_mock = MockCommand::new();
+        let mut sequence = Sequence::new();
+
+        // Setup the expectation
+        for arguments in [
+            vec!("--user", "daemon-reload"),
+            vec!("--user", "enable", "syncthing.service"),
+            vec!("--user", "restart", "syncthing.service")
+        ] {
+            command_mock.expect_execute()
+                .withf(move |args| args == arguments.as_slice())
+                .once()
+                .in_sequence(&mut sequence)
+                .returning(|_| Ok(true));
+        }
+
+        // Act
+        let sut = create_service_manager(&folder, command_mock, setup_terminal_output());
+        sut.configure(&services);
+
+        // Assert
+        assert_eq!(UNIT, fs::read_to_string(folder.join("user/syncthing.service")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn configure_does_not_reload_when_the_unit_is_up_to_date() {
+        // Arrange
+        let folder = create_test_folder("service", "up-to-date");
+        let services = vec!(ServiceConfiguration {
+            name: "backup.timer".to_string(),
+            scope: ServiceScope::System,
+            unit: Some(UNIT.to_string()),
+            state: Some(ServiceState::Started),
+            ..Default::default()
+        });
+        let mut command_mock = MockCommand::new();
+        fs::create_dir_all(folder.join("system")).unwrap();
+        fs::write(folder.join("system/backup.timer"), UNIT).unwrap();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(|args| args == &["start".to_string(), "backup.timer".to_string()])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = create_service_manager(&folder, command_mock, setup_terminal_output());
+        sut.configure(&services);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn configure_disables_and_stops_an_existing_unit() {
+        // Arrange
+        let folder = create_test_folder("service", "stop");
+        let services = vec!(ServiceConfiguration {
+            name: "syncthing.service".to_string(),
+            enabled: Some(false),
+            state: Some(ServiceState::Stopped),
+            ..Default::default()
+        });
+        let mut command_mock = MockCommand::new();
+        let mut sequence = Sequence::new();
+
+        // Setup the expectation
+        for arguments in [
+            vec!("--user", "disable", "syncthing.service"),
+            vec!("--user", "stop", "syncthing.service")
+        ] {
+            command_mock.expect_execute()
+                .withf(move |args| args == arguments.as_slice())
+                .once()
+                .in_sequence(&mut sequence)
+                .returning(|_| Ok(true));
+        }
+
+        // Act
+        let sut = create_service_manager(&folder, command_mock, setup_terminal_output());
+        sut.configure(&services);
+
+        // Assert
+        assert!(!folder.join("user").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn configure_calls_finish_with_warning_when_systemctl_fails() {
+        // Arrange
+        let folder = create_test_folder("service", "failed");
+        let services = vec!(ServiceConfiguration {
+            name: "syncthing.service".to_string(),
+            state: Some(ServiceState::Started),
+            ..Default::default()
+        });
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute().returning(|_| Ok(false));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message == "Unable to configure syncthing.service, systemctl start failed")
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = create_service_manager(
+            &folder,
+            command_mock,
+            Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>
+        );
+        sut.configure(&services);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn create_service_manager(
+        folder: &PathBuf,
+        command_mock: MockCommand,
+        terminal_output: Arc<dyn TerminalOutput>
+    ) -> Arc<dyn ServiceManager> {
+        return SystemdServiceManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &folder.join("user").to_string_lossy(),
+            &folder.join("system").to_string_lossy(),
+            &terminal_output
+        );
+    }
+}
@@ -0,0 +1,8 @@
+mod ssh_manager;
+mod open_ssh_manager;
+mod ssh_manager_factory;
+
+pub use ssh_manager::MockSshManager;
+pub use ssh_manager::SshManager;
+pub use ssh_manager_factory::SshManagerFactory;
+pub use ssh_manager_factory::SshManagerFactoryImpl;
\ No newline at end of file
@@ -0,0 +1,541 @@
+use std::any::Any;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+use sha2::{Digest, Sha256};
+use crate::command::Command;
+use crate::configuration::{KnownHostConfiguration, SshConfiguration, SshHostConfiguration, SshKeyConfiguration};
+use crate::managed_block::write_managed_block;
+use crate::path::{expand_home_directory, set_mode};
+use crate::ssh::ssh_manager::SshManager;
+use crate::terminal::TerminalOutput;
+
+const DEFAULT_KEY_TYPE: &str = "ed25519";
+
+pub struct OpenSshManager {
+    /// The ssh-keygen command
+    ssh_keygen_command: Arc<dyn Command>,
+
+    /// The ssh-keyscan command, used to retrieve the keys of known hosts
+    ssh_keyscan_command: Arc<dyn Command>,
+
+    /// Folder containing the ssh configuration of the user, e.g. `~/.ssh`
+    ssh_folder: String,
+
+    /// Terminal output where the status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl SshManager for OpenSshManager {
+    fn configure(&self, ssh: &SshConfiguration) {
+        for key in &ssh.keys {
+            self.generate_key(&key);
+        }
+
+        if !ssh.hosts.is_empty() {
+            let block = ssh.hosts.iter()
+                .map(|host| create_host_entry(&host))
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            self.write_file("config", "hosts", &block);
+        }
+
+        if !ssh.known_hosts.is_empty() {
+            self.write_known_hosts(&ssh.known_hosts);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OpenSshManager {
+    pub fn new(
+        ssh_keygen_command: &Arc<dyn Command>,
+        ssh_keyscan_command: &Arc<dyn Command>,
+        ssh_folder: &str,
+        terminal_output: &Arc<dyn TerminalOutput>
+    ) -> Arc<dyn SshManager> {
+        return Arc::new(Self {
+            ssh_keygen_command: ssh_keygen_command.clone(),
+            ssh_keyscan_command: ssh_keyscan_command.clone(),
+            ssh_folder: ssh_folder.to_string(),
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn generate_key(&self, key: &SshKeyConfiguration) {
+        let key_type = key.key_type.as_deref().unwrap_or(DEFAULT_KEY_TYPE);
+        let path = match &key.path {
+            Some(path) => PathBuf::from(expand_home_directory(&path)),
+            None => Path::new(&self.ssh_folder).join(format!("id_{}", &key_type))
+        };
+        let name = path.to_string_lossy().to_string();
+
+        self.terminal_output.loading(&format!("Generating {}", &name));
+
+        if path.exists() {
+            self.terminal_output.finish_with_success(&format!("{} already exists", &name));
+            return;
+        }
+
+        let result = self.create_ssh_folder()
+            .map_err(|e| e.to_string())
+            .and_then(|_| self.ssh_keygen_command.execute(&get_arguments(&key_type, &name, &key)));
+
+        if result.is_ok_and(|output| output) {
+            self.terminal_output.finish_with_success(&format!("Successfully generated {}", &name));
+        } else {
+            self.terminal_output.finish_with_warning(&format!("Unable to generate {}", &name));
+        }
+    }
+
+    /// Write the known hosts, which are left alone when the
+    /// key of one of the hosts can't be verified
+    fn write_known_hosts(&self, known_hosts: &Vec<KnownHostConfiguration>) {
+        let mut entries = Vec::new();
+
+        for known_host in known_hosts {
+            match self.get_known_host_entries(&known_host) {
+                Ok(host_entries) => entries.extend(host_entries),
+                Err(error) => {
+                    self.terminal_output.finish_with_warning(
+                        &format!("Unable to verify the key of {}, {}", &known_host.host, &error)
+                    );
+                    return;
+                }
+            }
+        }
+
+        self.write_file("known_hosts", "known hosts", &entries.join("\n"));
+    }
+
+    /// Get the known hosts entries of the host, a configured key is used as is
+    /// while the keys retrieved by ssh-keyscan are only used when their
+    /// fingerprint matches the configured fingerprint
+    fn get_known_host_entries(&self, known_host: &KnownHostConfiguration) -> Result<Vec<String>, String> {
+        if let Some(key) = &known_host.key {
+            return Ok(vec!(format!("{} {}", &known_host.host, &key)));
+        }
+
+        let Some(fingerprint) = &known_host.fingerprint else {
+            return Err("neither a key nor a fingerprint is configured".to_string());
+        };
+
+        self.terminal_output.loading(&format!("Scanning the keys of {}", &known_host.host));
+
+        let entries: Vec<String> = self.ssh_keyscan_command
+            .output(&[known_host.host.to_string()])?
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter(|line| line.split_whitespace()
+                .nth(2)
+                .and_then(|key| get_fingerprint(&key))
+                .is_some_and(|key_fingerprint| key_fingerprint == fingerprint.trim_end_matches('=')))
+            .map(|line| line.to_string())
+            .collect();
+
+        if entries.is_empty() {
+            return Err(format!("none of its keys match {}", &fingerprint));
+        }
+
+        return Ok(entries);
+    }
+
+    /// Write the block between the upset marker comments in the
+    /// file inside of the ssh folder, which is only readable by the user
+    fn write_file(&self, file_name: &str, block_name: &str, block: &str) {
+        let path = Path::new(&self.ssh_folder).join(&file_name);
+        let name = path.to_string_lossy().to_string();
+
+        self.terminal_output.loading(&format!("Writing {}", &name));
+
+        let result = self.create_ssh_folder()
+            .and_then(|_| write_managed_block(&path, "#", &block_name, Some(&block)))
+            .and_then(|changed| set_mode(&path, 0o600).map(|_| changed));
+
+        match result {
+            Ok(true) => self.terminal_output.finish_with_success(
+                &format!("Successfully wrote {}", &name)
+            ),
+            Ok(false) => self.terminal_output.finish_with_success(
+                &format!("{} is up to date", &name)
+            ),
+            Err(_) => self.terminal_output.finish_with_warning(
+                &format!("Unable to write {}", &name)
+            )
+        }
+    }
+
+    /// Create the ssh folder, which is only accessible by the user
+    fn create_ssh_folder(&self) -> io::Result<()> {
+        let ssh_folder = Path::new(&self.ssh_folder);
+
+        if ssh_folder.is_dir() {
+            return Ok(());
+        }
+
+        fs::create_dir_all(&ssh_folder)?;
+        return set_mode(&ssh_folder, 0o700);
+    }
+}
+
+/// Get the SHA256 fingerprint of the base64 encoded public key
+/// in the format used by `ssh-keygen -l`, e.g. `SHA256:<base64 digest>`
+fn get_fingerprint(key: &str) -> Option<String> {
+    let key = STANDARD.decode(&key).ok()?;
+
+    return Some(format!("SHA256:{}", STANDARD_NO_PAD.encode(Sha256::digest(&key))));
+}
+
+/// ssh-keygen only accepts the passphrase as an argument, so it's visible
+/// to other users in the process list while the key is being generated
+fn get_arguments(key_type: &str, path: &str, key: &SshKeyConfiguration) -> Vec<String> {
+    let mut arguments = vec!(
+        "-q".to_string(),
+        "-t".to_string(),
+        key_type.to_string(),
+        "-f".to_string(),
+        path.to_string(),
+        "-N".to_string(),
+        key.passphrase.clone().unwrap_or_default()
+    );
+
+    if let Some(comment) = &key.comment {
+        arguments.push("-C".to_string());
+        arguments.push(comment.to_string());
+    }
+
+    return arguments;
+}
+
+/// Create the entry for the host in the format of the ssh config
+fn create_host_entry(host: &SshHostConfiguration) -> String {
+    let mut lines = vec!(format!("Host {}", &host.host));
+    let options = [
+        ("HostName", host.hostname.clone()),
+        ("User", host.user.clone()),
+        ("Port", host.port.map(|port| port.to_string())),
+        ("IdentityFile", host.identity_file.clone())
+    ];
+
+    for (option, value) in options {
+        if let Some(value) = value {
+            lines.push(format!("    {} {}", &option, &value));
+        }
+    }
+
+    for (option, value) in &host.options {
+        lines.push(format!("    {} {}", &option, &value));
+    }
+
+    return lines.join("\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::sync::Arc;
+    use crate::command::{Command, MockCommand};
+    use crate::configuration::{KnownHostConfiguration, SshConfiguration, SshHostConfiguration, SshKeyConfiguration};
+    use crate::ssh::open_ssh_manager::{create_host_entry, OpenSshManager};
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    #[test]
+    fn create_host_entry_writes_the_configured_options() {
+        // Arrange
+        let host = SshHostConfiguration {
+            host: "github.com".to_string(),
+            user: Some("git".to_string()),
+            identity_file: Some("~/.ssh/id_ed25519".to_string()),
+            options: BTreeMap::from([("IdentitiesOnly".to_string(), "yes".to_string())]),
+            ..Default::default()
+        };
+
+        // Act
+        let result = create_host_entry(&host);
+
+        // Assert
+        assert_eq!(
+            "Host github.com\n    User git\n    IdentityFile ~/.ssh/id_ed25519\n    IdentitiesOnly yes",
+            result
+        );
+    }
+
+    #[test]
+    fn configure_generates_the_key_when_it_does_not_exist() {
+        // Arrange
+        let folder = create_test_folder("ssh", "generate");
+        let ssh_folder = folder.join(".ssh");
+        let expected_path = ssh_folder.join("id_ed25519").to_string_lossy().to_string();
+        let ssh = SshConfiguration {
+            keys: vec!(SshKeyConfiguration {
+                comment: Some("bart@upset".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute()
+            .withf(move |args| args == &[
+                "-q".to_string(), "-t".to_string(), "ed25519".to_string(),
+                "-f".to_string(), expected_path.clone(),
+                "-N".to_string(), "".to_string(),
+                "-C".to_string(), "bart@upset".to_string()
+            ])
+            .once()
+            .returning(|_| Ok(true));
+
+        // Act
+        let sut = OpenSshManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_command_mock(),
+            &ssh_folder.to_string_lossy(),
+            &setup_terminal_output()
+        );
+        sut.configure(&ssh);
+
+        // Assert
+        assert!(ssh_folder.is_dir());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn configure_does_not_generate_the_key_when_it_exists() {
+        // Arrange
+        let folder = create_test_folder("ssh", "exists");
+        let ssh = SshConfiguration {
+            keys: vec!(SshKeyConfiguration {
+                key_type: Some("rsa".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(folder.join("id_rsa"), "private key").unwrap();
+
+        // Setup the expectation
+        command_mock.expect_execute().never();
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("already exists"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = OpenSshManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_command_mock(),
+            &folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.configure(&ssh);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn configure_calls_finish_with_warning_when_the_key_can_not_be_generated() {
+        // Arrange
+        let folder = create_test_folder("ssh", "failed");
+        let ssh = SshConfiguration {
+            keys: vec!(SshKeyConfiguration::default()),
+            ..Default::default()
+        };
+        let mut command_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        command_mock.expect_execute().returning(|_| Err(String::default()));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = OpenSshManager::new(
+            &(Arc::new(command_mock) as Arc<dyn Command>),
+            &setup_command_mock(),
+            &folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.configure(&ssh);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn configure_writes_the_hosts_and_the_known_hosts() {
+        // Arrange
+        let folder = create_test_folder("ssh", "hosts");
+        let ssh = SshConfiguration {
+            hosts: vec!(SshHostConfiguration {
+                host: "github.com".to_string(),
+                user: Some("git".to_string()),
+                ..Default::default()
+            }),
+            known_hosts: vec!(KnownHostConfiguration::from(
+                "github.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl".to_string()
+            )),
+            ..Default::default()
+        };
+        fs::write(folder.join("config"), "Host *\n    AddKeysToAgent yes\n").unwrap();
+
+        // Act
+        let sut = OpenSshManager::new(&setup_command_mock(), &setup_command_mock(), &folder.to_string_lossy(), &setup_terminal_output());
+        sut.configure(&ssh);
+
+        // Assert
+        assert_eq!(
+            "Host *\n    AddKeysToAgent yes\n# BEGIN upset hosts\nHost github.com\n    User git\n# END upset hosts\n",
+            fs::read_to_string(folder.join("config")).unwrap()
+        );
+        assert_eq!(
+            "# BEGIN upset known hosts\ngithub.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl\n# END upset known hosts\n",
+            fs::read_to_string(folder.join("known_hosts")).unwrap()
+        );
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn configure_calls_finish_with_success_when_the_known_hosts_are_up_to_date() {
+        // Arrange
+        let folder = create_test_folder("ssh", "up-to-date");
+        let ssh = SshConfiguration {
+            known_hosts: vec!(KnownHostConfiguration::from(
+                "github.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl".to_string()
+            )),
+            ..Default::default()
+        };
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        OpenSshManager::new(&setup_command_mock(), &setup_command_mock(), &folder.to_string_lossy(), &setup_terminal_output())
+            .configure(&ssh);
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = OpenSshManager::new(
+            &setup_command_mock(),
+            &setup_command_mock(),
+            &folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.configure(&ssh);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn configure_only_writes_the_scanned_keys_matching_the_fingerprint() {
+        // Arrange
+        let folder = create_test_folder("ssh", "fingerprint");
+        let ssh = SshConfiguration {
+            known_hosts: vec!(KnownHostConfiguration {
+                host: "github.com".to_string(),
+                fingerprint: Some("SHA256:+DiY3wvvV6TuJJhbpZisF/zLDA0zPMSvHdkr4UvCOqU".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut ssh_keyscan_mock = MockCommand::new();
+
+        // Setup the expectation
+        ssh_keyscan_mock.expect_output()
+            .withf(|args| args == &["github.com".to_string()])
+            .once()
+            .returning(|_| Ok("\
+# github.com:22 SSH-2.0-babeld-05989c77
+github.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl
+github.com ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABgQCj7ndNxQowgcQnjshcLrqPEiiphnt
+".to_string()));
+
+        // Act
+        let sut = OpenSshManager::new(
+            &setup_command_mock(),
+            &(Arc::new(ssh_keyscan_mock) as Arc<dyn Command>),
+            &folder.to_string_lossy(),
+            &setup_terminal_output()
+        );
+        sut.configure(&ssh);
+
+        // Assert
+        assert_eq!(
+            "# BEGIN upset known hosts\ngithub.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl\n# END upset known hosts\n",
+            fs::read_to_string(folder.join("known_hosts")).unwrap()
+        );
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn configure_leaves_the_known_hosts_alone_when_the_fingerprint_does_not_match() {
+        // Arrange
+        let folder = create_test_folder("ssh", "fingerprint-mismatch");
+        let ssh = SshConfiguration {
+            known_hosts: vec!(KnownHostConfiguration {
+                host: "github.com".to_string(),
+                fingerprint: Some("SHA256:uNiVztksCsDhcc0u9e8BujQXVUpKZIDTMczCvj3tD2s".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut ssh_keyscan_mock = MockCommand::new();
+        let mut terminal_output_mock = MockTerminalOutput::new();
+
+        // Setup the expectation
+        ssh_keyscan_mock.expect_output()
+            .returning(|_| Ok("github.com ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl\n".to_string()));
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .withf(|message| message.starts_with("Unable to verify the key of github.com"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = OpenSshManager::new(
+            &setup_command_mock(),
+            &(Arc::new(ssh_keyscan_mock) as Arc<dyn Command>),
+            &folder.to_string_lossy(),
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        sut.configure(&ssh);
+
+        // Assert
+        assert!(!folder.join("known_hosts").exists());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn setup_command_mock() -> Arc<dyn Command> {
+        let mut command_mock = MockCommand::new();
+
+        // Setup the expectation
+        command_mock.expect_execute().never();
+
+        return Arc::new(command_mock);
+    }
+}
@@ -0,0 +1,10 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::SshConfiguration;
+
+/// Public methods to call for configuring ssh
+#[automock]
+pub trait SshManager {
+    fn configure(&self, ssh: &SshConfiguration);
+    fn as_any(&self) -> &dyn Any;
+}
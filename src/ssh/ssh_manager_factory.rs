@@ -0,0 +1,60 @@
+use std::path::Path;
+use std::sync::Arc;
+use crate::commands::{SshKeygenCommand, SshKeyscanCommand};
+use crate::path::home_directory;
+use crate::ssh::open_ssh_manager::OpenSshManager;
+use crate::ssh::ssh_manager::SshManager;
+use crate::terminal::TerminalOutput;
+
+pub trait SshManagerFactory {
+    fn get_ssh_manager(&self) -> Option<Arc<dyn SshManager>>;
+}
+
+pub struct SshManagerFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl SshManagerFactory for SshManagerFactoryImpl {
+    fn get_ssh_manager(&self) -> Option<Arc<dyn SshManager>> {
+        let ssh_folder = Path::new(&home_directory()?).join(".ssh");
+
+        return Some(OpenSshManager::new(
+            &SshKeygenCommand::new(),
+            &SshKeyscanCommand::new(),
+            &ssh_folder.to_string_lossy(),
+            &self.terminal_output
+        ));
+    }
+}
+
+impl SshManagerFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn SshManagerFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::ssh::open_ssh_manager::OpenSshManager;
+    use crate::ssh::ssh_manager_factory::SshManagerFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[test]
+    fn get_ssh_manager_returns_open_ssh_manager() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = SshManagerFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_ssh_manager();
+
+        // Assert
+        assert!(result.is_some());
+        assert!(result.unwrap().as_any().is::<OpenSshManager>());
+    }
+}
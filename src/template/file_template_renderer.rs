@@ -0,0 +1,210 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::configuration::TemplateConfiguration;
+use crate::path::{expand_home_directory, resolve_destination, set_mode};
+use crate::template::machine_variables::get_machine_variables;
+use crate::template::template_engine::render_template;
+use crate::template::template_renderer::TemplateRenderer;
+use crate::terminal::TerminalOutput;
+
+/// Outcome of rendering a single template
+#[derive(Debug, PartialEq)]
+enum RenderResult {
+    Written,
+    UpToDate
+}
+
+pub struct FileTemplateRenderer {
+    /// Terminal output where the render status is written to
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl TemplateRenderer for FileTemplateRenderer {
+    fn render(&self, templates: &Vec<TemplateConfiguration>) {
+        let machine_variables = get_machine_variables();
+
+        for template in templates {
+            self.render_template(&template, &machine_variables);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl FileTemplateRenderer {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn TemplateRenderer> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+
+    fn render_template(&self, template: &TemplateConfiguration, machine_variables: &BTreeMap<String, String>) {
+        let source = PathBuf::from(expand_home_directory(&template.source));
+        let destination = resolve_destination(&expand_home_directory(&template.destination), &source);
+        let mut variables = machine_variables.clone();
+        variables.extend(template.variables.clone());
+
+        self.terminal_output.loading(&format!("Rendering {}", &template.source));
+
+        match render(&source, &destination, &variables, template.mode) {
+            Ok(RenderResult::Written) => self.terminal_output.finish_with_success(
+                &format!("Successfully rendered {} to {}", &template.source, &template.destination)
+            ),
+            Ok(RenderResult::UpToDate) => self.terminal_output.finish_with_success(
+                &format!("{} is up to date", &template.destination)
+            ),
+            Err(error) => self.terminal_output.finish_with_warning(
+                &format!("Unable to render {}, {}", &template.source, &error)
+            )
+        }
+    }
+}
+
+/// Render the template into the destination, which is
+/// only written when the rendered contents have changed
+fn render(
+    source: &Path,
+    destination: &Path,
+    variables: &BTreeMap<String, String>,
+    mode: Option<u32>
+) -> Result<RenderResult, String> {
+    let template = fs::read_to_string(&source).map_err(|e| e.to_string())?;
+    let rendered = render_template(&template, &variables)?;
+    let mut result = RenderResult::UpToDate;
+
+    if fs::read_to_string(&destination).ok().as_ref() != Some(&rendered) {
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(&parent).map_err(|e| e.to_string())?;
+        }
+
+        fs::write(&destination, &rendered).map_err(|e| e.to_string())?;
+        result = RenderResult::Written;
+    }
+
+    if let Some(mode) = mode {
+        set_mode(&destination, mode).map_err(|e| e.to_string())?;
+    }
+
+    return Ok(result);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use crate::configuration::TemplateConfiguration;
+    use crate::template::file_template_renderer::FileTemplateRenderer;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::{create_test_folder, delete_test_folder, setup_terminal_output};
+
+    #[test]
+    fn render_writes_the_rendered_template_to_the_destination() {
+        // Arrange
+        let folder = create_test_folder("template", "render");
+        let templates = vec!(TemplateConfiguration {
+            variables: BTreeMap::from([("email".to_string(), "bart@bartkessels.net".to_string())]),
+            ..create_template(&folder, "gitconfig", "home/.gitconfig")
+        });
+        fs::write(folder.join("gitconfig"), "email = {{ email }}\nos = {{ os }}").unwrap();
+
+        // Act
+        let sut = FileTemplateRenderer::new(&setup_terminal_output());
+        sut.render(&templates);
+
+        // Assert
+        assert_eq!(
+            format!("email = bart@bartkessels.net\nos = {}", env::consts::OS),
+            fs::read_to_string(folder.join("home/.gitconfig")).unwrap()
+        );
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn render_prefers_the_configured_variables_over_the_machine_variables() {
+        // Arrange
+        let folder = create_test_folder("template", "override");
+        let templates = vec!(TemplateConfiguration {
+            variables: BTreeMap::from([("hostname".to_string(), "upset".to_string())]),
+            ..create_template(&folder, "npmrc", ".npmrc")
+        });
+        fs::write(folder.join("npmrc"), "{{ hostname }}").unwrap();
+
+        // Act
+        let sut = FileTemplateRenderer::new(&setup_terminal_output());
+        sut.render(&templates);
+
+        // Assert
+        assert_eq!("upset", fs::read_to_string(folder.join(".npmrc")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn render_calls_finish_with_success_when_the_destination_is_up_to_date() {
+        // Arrange
+        let folder = create_test_folder("template", "up-to-date");
+        let templates = vec!(create_template(&folder, "npmrc", ".npmrc"));
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(folder.join("npmrc"), "registry=https://registry.npmjs.org/").unwrap();
+        fs::write(folder.join(".npmrc"), "registry=https://registry.npmjs.org/").unwrap();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_success()
+            .withf(|message| message.ends_with("is up to date"))
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = FileTemplateRenderer::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.render(&templates);
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    #[test]
+    fn render_calls_finish_with_warning_and_keeps_the_destination_when_a_variable_is_undefined() {
+        // Arrange
+        let folder = create_test_folder("template", "undefined");
+        let templates = vec!(create_template(&folder, "npmrc", ".npmrc"));
+        let mut terminal_output_mock = MockTerminalOutput::new();
+        fs::write(folder.join("npmrc"), "//registry.npmjs.org/:_authToken={{ token }}").unwrap();
+        fs::write(folder.join(".npmrc"), "registry=https://registry.npmjs.org/").unwrap();
+
+        // Setup the expectation
+        terminal_output_mock.expect_loading().returning(|_| {});
+        terminal_output_mock.expect_finish_with_warning()
+            .once()
+            .returning(|_| {});
+
+        // Act
+        let sut = FileTemplateRenderer::new(&(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>));
+        sut.render(&templates);
+
+        // Assert
+        assert_eq!("registry=https://registry.npmjs.org/", fs::read_to_string(folder.join(".npmrc")).unwrap());
+
+        // Teardown
+        delete_test_folder(&folder);
+    }
+
+    fn create_template(folder: &PathBuf, source: &str, destination: &str) -> TemplateConfiguration {
+        return TemplateConfiguration {
+            source: folder.join(&source).to_string_lossy().to_string(),
+            destination: folder.join(&destination).to_string_lossy().to_string(),
+            ..Default::default()
+        };
+    }
+}
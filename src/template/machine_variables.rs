@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::process;
+use crate::path::home_directory;
+
+/// Get the variables describing the current machine: `hostname`,
+/// `user`, `home`, `os` (e.g. `linux` or `macos`) and `arch`
+pub fn get_machine_variables() -> BTreeMap<String, String> {
+    let mut variables = BTreeMap::from([
+        ("os".to_string(), env::consts::OS.to_string()),
+        ("arch".to_string(), env::consts::ARCH.to_string())
+    ]);
+
+    if let Some(hostname) = get_hostname() {
+        variables.insert("hostname".to_string(), hostname);
+    }
+
+    if let Some(user) = get_user() {
+        variables.insert("user".to_string(), user);
+    }
+
+    if let Some(home) = home_directory() {
+        variables.insert("home".to_string(), home);
+    }
+
+    return variables;
+}
+
+/// Windows exposes the hostname as an environment variable, Linux
+/// exposes it as a file and macOS only through the hostname command
+fn get_hostname() -> Option<String> {
+    return env::var("COMPUTERNAME").ok()
+        .or_else(|| fs::read_to_string("/proc/sys/kernel/hostname").ok())
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .or_else(|| process::Command::new("hostname").output().ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).to_string()))
+        .map(|hostname| hostname.trim().to_string())
+        .filter(|hostname| !hostname.is_empty());
+}
+
+fn get_user() -> Option<String> {
+    return env::var("USER")
+        .or_else(|_| env::var("USERNAME"))
+        .ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use crate::template::machine_variables::get_machine_variables;
+
+    #[test]
+    fn get_machine_variables_contains_the_os_and_architecture() {
+        // Act
+        let result = get_machine_variables();
+
+        // Assert
+        assert_eq!(Some(&env::consts::OS.to_string()), result.get("os"));
+        assert_eq!(Some(&env::consts::ARCH.to_string()), result.get("arch"));
+    }
+
+    #[test]
+    fn get_machine_variables_contains_the_hostname() {
+        // Act
+        let result = get_machine_variables();
+
+        // Assert
+        assert!(result.get("hostname").is_some_and(|hostname| !hostname.is_empty()));
+    }
+}
@@ -0,0 +1,10 @@
+mod template_renderer;
+mod template_engine;
+mod machine_variables;
+mod file_template_renderer;
+mod template_renderer_factory;
+
+pub use template_renderer::MockTemplateRenderer;
+pub use template_renderer::TemplateRenderer;
+pub use template_renderer_factory::TemplateRendererFactory;
+pub use template_renderer_factory::TemplateRendererFactoryImpl;
\ No newline at end of file
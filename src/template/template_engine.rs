@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use std::env;
+
+/// Replace the `{{ name }}` placeholders in the template with the values of the
+/// variables, `{{ env.NAME }}` placeholders are replaced with environment variables
+///
+/// A literal `{{` is written as `{{{{`, which keeps it from starting a placeholder.
+/// Returns an error for placeholders without a value, so typing mistakes
+/// don't end up in the rendered file
+pub fn render_template(template: &str, variables: &BTreeMap<String, String>) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut remainder = template;
+
+    while let Some(start) = remainder.find("{{") {
+        rendered.push_str(&remainder[..start]);
+
+        if let Some(escaped) = remainder[start..].strip_prefix("{{{{") {
+            rendered.push_str("{{");
+            remainder = escaped;
+            continue;
+        }
+
+        let placeholder = &remainder[start + 2..];
+        let end = placeholder.find("}}")
+            .ok_or("Placeholder is never closed".to_string())?;
+
+        rendered.push_str(&get_value(placeholder[..end].trim(), &variables)?);
+        remainder = &placeholder[end + 2..];
+    }
+
+    rendered.push_str(remainder);
+
+    return Ok(rendered);
+}
+
+fn get_value(name: &str, variables: &BTreeMap<String, String>) -> Result<String, String> {
+    if let Some(value) = variables.get(name) {
+        return Ok(value.to_string());
+    }
+
+    if let Some(environment_variable) = name.strip_prefix("env.") {
+        return env::var(environment_variable)
+            .map_err(|_| format!("Environment variable {} is not set", &environment_variable));
+    }
+
+    return Err(format!("Variable {} is not defined", &name));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::env;
+    use crate::template::template_engine::render_template;
+
+    #[test]
+    fn render_template_replaces_the_placeholders_with_the_variables() {
+        // Arrange
+        let template = "[user]\n    name = {{ name }}\n    email = {{email}}\n";
+        let variables = BTreeMap::from([
+            ("name".to_string(), "Bart Kessels".to_string()),
+            ("email".to_string(), "bart@bartkessels.net".to_string())
+        ]);
+
+        // Act
+        let result = render_template(&template, &variables);
+
+        // Assert
+        assert_eq!(
+            "[user]\n    name = Bart Kessels\n    email = bart@bartkessels.net\n",
+            result.unwrap()
+        );
+    }
+
+    #[test]
+    fn render_template_replaces_the_environment_variables() {
+        // Arrange
+        let template = "cache={{ env.PATH }}";
+
+        // Act
+        let result = render_template(&template, &BTreeMap::new());
+
+        // Assert
+        assert_eq!(format!("cache={}", env::var("PATH").unwrap()), result.unwrap());
+    }
+
+    #[test]
+    fn render_template_writes_escaped_braces_literally() {
+        // Arrange
+        let template = "{{{{ name }} is rendered as {{ name }}";
+        let variables = BTreeMap::from([("name".to_string(), "upset".to_string())]);
+
+        // Act
+        let result = render_template(&template, &variables);
+
+        // Assert
+        assert_eq!("{{ name }} is rendered as upset", result.unwrap());
+    }
+
+    #[test]
+    fn render_template_returns_error_for_undefined_variables() {
+        // Act
+        let result = render_template("{{ undefined }}", &BTreeMap::new());
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn render_template_returns_error_for_unset_environment_variables() {
+        // Act
+        let result = render_template("{{ env.UPSET_UNSET_VARIABLE }}", &BTreeMap::new());
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn render_template_returns_error_for_placeholders_which_are_never_closed() {
+        // Arrange
+        let variables = BTreeMap::from([("name".to_string(), "upset".to_string())]);
+
+        // Act
+        let result = render_template("{{ name", &variables);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
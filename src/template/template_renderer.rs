@@ -0,0 +1,13 @@
+use std::any::Any;
+use mockall::automock;
+use crate::configuration::TemplateConfiguration;
+
+/// Public methods to call for rendering templates
+#[automock]
+pub trait TemplateRenderer {
+    /// Render the templates into their destinations
+    fn render(&self, templates: &Vec<TemplateConfiguration>);
+
+    /// Get object reference
+    fn as_any(&self) -> &dyn Any;
+}
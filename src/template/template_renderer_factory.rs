@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use crate::template::file_template_renderer::FileTemplateRenderer;
+use crate::template::template_renderer::TemplateRenderer;
+use crate::terminal::TerminalOutput;
+
+pub trait TemplateRendererFactory {
+    fn get_template_renderer(&self) -> Arc<dyn TemplateRenderer>;
+}
+
+pub struct TemplateRendererFactoryImpl {
+    terminal_output: Arc<dyn TerminalOutput>
+}
+
+impl TemplateRendererFactory for TemplateRendererFactoryImpl {
+    fn get_template_renderer(&self) -> Arc<dyn TemplateRenderer> {
+        return FileTemplateRenderer::new(&self.terminal_output);
+    }
+}
+
+impl TemplateRendererFactoryImpl {
+    pub fn new(terminal_output: &Arc<dyn TerminalOutput>) -> Arc<dyn TemplateRendererFactory> {
+        return Arc::new(Self {
+            terminal_output: terminal_output.clone()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::template::file_template_renderer::FileTemplateRenderer;
+    use crate::template::template_renderer_factory::TemplateRendererFactoryImpl;
+    use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+    #[test]
+    fn get_template_renderer_returns_file_template_renderer() {
+        // Arrange
+        let terminal_output_mock = MockTerminalOutput::new();
+
+        // Act
+        let sut = TemplateRendererFactoryImpl::new(
+            &(Arc::new(terminal_output_mock) as Arc<dyn TerminalOutput>)
+        );
+        let result = sut.get_template_renderer();
+
+        // Assert
+        assert!(result.as_any().is::<FileTemplateRenderer>());
+    }
+}
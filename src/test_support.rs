@@ -0,0 +1,37 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use crate::terminal::{MockTerminalOutput, TerminalOutput};
+
+/// Create an empty folder for a test inside the temporary folder, named after the
+/// area of the tests and the test itself so tests running in parallel don't share it
+pub fn create_test_folder(area: &str, name: &str) -> PathBuf {
+    let folder = env::temp_dir().join(format!("upset-{}-{}", &area, &name));
+    _ = fs::remove_dir_all(&folder);
+    fs::create_dir_all(&folder).expect("Unable to create the test folder");
+
+    return folder;
+}
+
+pub fn delete_test_folder(folder: &PathBuf) {
+    fs::remove_dir_all(&folder).expect("Unable to delete the test folder");
+}
+
+/// Create a terminal output mock which accepts every message
+pub fn setup_terminal_output_mock() -> MockTerminalOutput {
+    let mut terminal_output_mock = MockTerminalOutput::new();
+
+    // Setup the expectations
+    terminal_output_mock.expect_loading().returning(|_| {});
+    terminal_output_mock.expect_finish_with_success().returning(|_| {});
+    terminal_output_mock.expect_finish_with_warning().returning(|_| {});
+
+    return terminal_output_mock;
+}
+
+/// Create a terminal output which accepts every message, for tests
+/// which don't set any expectations on the terminal output themselves
+pub fn setup_terminal_output() -> Arc<dyn TerminalOutput> {
+    return Arc::new(setup_terminal_output_mock());
+}
@@ -68,6 +68,7 @@ mod tests {
     use mockall::Sequence;
     use crate::command::{Command, MockCommand};
     use crate::terminal::{MockTerminalOutput, TerminalOutput};
+    use crate::test_support::setup_terminal_output_mock;
     use crate::version_control::git_version_control_system::GitVersionControlSystem;
 
     #[test]
@@ -227,15 +228,4 @@ mod tests {
         );
         _ = sut.download(&repositories);
     }
-
-    fn setup_terminal_output_mock() -> MockTerminalOutput {
-        let mut terminal_output_mock = MockTerminalOutput::new();
-
-        // Setup the expectations
-        terminal_output_mock.expect_loading().returning(|_| {});
-        terminal_output_mock.expect_finish_with_success().returning(|_| {});
-        terminal_output_mock.expect_finish_with_warning().returning(|_| {});
-
-        return terminal_output_mock;
-    }
 }
\ No newline at end of file